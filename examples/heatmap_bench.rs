@@ -0,0 +1,39 @@
+//! Times the drawing of a large heatmap
+//!
+//! Compare the timings with and without the `rayon` feature:
+//! ```shell
+//! cargo run --release --example heatmap_bench
+//! cargo run --release --example heatmap_bench --features rayon
+//! ```
+use std::time::Instant;
+
+fn main() {
+    let n = 4000;
+    let data: Vec<f64> = (0..n * n)
+        .map(|k| {
+            let (x, y) = ((k % n) as f64 / n as f64, (k / n) as f64 / n as f64);
+            (20. * x).sin() * (20. * y).cos()
+        })
+        .collect();
+    let now = Instant::now();
+    let _: complot::Heatmap = (
+        (data.as_slice(), (n, n)),
+        Some(
+            complot::Config::new()
+                .filename(
+                    std::env::temp_dir()
+                        .join("heatmap-bench.png")
+                        .to_string_lossy(),
+                )
+                .over_sampling_factor(1),
+        ),
+    )
+        .into();
+    println!(
+        "{}x{} heatmap drawn in {:.3}s (rayon: {})",
+        n,
+        n,
+        now.elapsed().as_secs_f64(),
+        cfg!(feature = "rayon")
+    );
+}
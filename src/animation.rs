@@ -0,0 +1,59 @@
+use plotters::{coord::Shift, prelude::*};
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// GIF animation drawn frame by frame
+///
+/// Each call to [`Animation::frame`] draws a graph on the drawing area of the animation
+/// and appends it to the GIF file, the file is finalized when the animation is dropped or with [`Animation::finish`]
+/// ```
+/// fn main() -> complot::Result<()> {
+///     let mut animation = complot::Animation::new("animation.gif", (400, 350), 200)?;
+///     for t in 0..5 {
+///         let data: Vec<f64> = (0..400)
+///             .map(|k| ((k / 20) as f64 / 4. - t as f64).sin() * ((k % 20) as f64 / 4.).cos())
+///             .collect();
+///         animation.frame(|area| {
+///             complot::Heatmap::draw_on(
+///                 area,
+///                 (data.as_slice(), (20, 20)),
+///                 Some(complot::Config::new().title(format!("t = {}", t))),
+///             )
+///         })?;
+///     }
+///     assert_eq!(animation.finish(), "animation.gif");
+///     Ok(())
+/// }
+/// ```
+pub struct Animation {
+    root: DrawingArea<BitMapBackend<'static>, Shift>,
+    path: String,
+}
+impl Animation {
+    /// Creates the GIF file `filename` of `size` pixels with `frame_delay` milliseconds between frames
+    pub fn new<S: Into<String>>(filename: S, size: (u32, u32), frame_delay: u32) -> Result<Self> {
+        let path = filename.into();
+        let root = BitMapBackend::gif(&path, size, frame_delay)
+            .map_err(|e| crate::Error::Backend(Box::new(e)))?
+            .into_drawing_area();
+        Ok(Self { root, path })
+    }
+    /// Draws the next frame on a white background with `draw` and appends it to the animation
+    pub fn frame<F>(&mut self, draw: F) -> Result<()>
+    where
+        F: FnOnce(&DrawingArea<BitMapBackend<'static>, Shift>) -> Result<()>,
+    {
+        self.root.fill(&WHITE)?;
+        draw(&self.root)?;
+        self.root.present()?;
+        Ok(())
+    }
+    /// Returns the path of the GIF file
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+    /// Finalizes the GIF file and returns its path
+    pub fn finish(self) -> String {
+        self.path
+    }
+}
@@ -0,0 +1,143 @@
+use super::{Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Stacked area charts
+///
+/// Each series (y1, y2, ...) is stacked on top of the previous ones,
+/// the top edge is the total y1+y2+...
+/// ```
+/// let _: complot::StackedArea = (
+///     (0..100).map(|k| {
+///         let x = k as f64 / 10.;
+///         (x, vec![1. + x.sin().abs(), x.sqrt(), 0.5])
+///     }),
+///     Some(
+///         complot::Config::new()
+///             .filename("stacked_area.svg")
+///             .legend(vec!["a", "b", "c"]),
+///     ),
+/// )
+///     .into();
+/// ```
+pub struct StackedArea {
+    path: String,
+}
+impl_path!(StackedArea);
+
+impl Draw for StackedArea {
+    type Data = [(f64, Vec<f64>)];
+    fn draw<DB>(xy: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if xy.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        let n_y = xy[0].1.len();
+        // cumulative sums of the series
+        let stacks: Vec<(f64, Vec<f64>)> = xy
+            .iter()
+            .map(|(x, y)| {
+                (
+                    *x,
+                    y.iter()
+                        .scan(0f64, |total, y| {
+                            *total += y;
+                            Some(*total)
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+        let (x_min, x_max) = stacks
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), (x, _)| {
+                (a.min(*x), b.max(*x))
+            });
+        let (y_min, y_max) = stacks
+            .iter()
+            .flat_map(|(_, y)| y.iter())
+            .fold((0f64, 0f64), |(a, b), y| (a.min(*y), b.max(*y)));
+        let xrange = config.xaxis.range.clone().unwrap_or(x_min..x_max);
+        let yrange = config.yaxis.range.clone().unwrap_or(y_min..y_max);
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        )?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = config.xaxis.scaled_label() {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = config.yaxis.scaled_label() {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+
+        let colors: Vec<_> = config.color_cycle().take(n_y).collect();
+        // the areas are drawn from the top so each one covers the bottom of the previous one
+        for k in (0..n_y).rev() {
+            let rgb = colors[k];
+            let series = chart.draw_series(
+                AreaSeries::new(
+                    stacks
+                        .iter()
+                        .filter_map(|(x, y)| y.get(k).map(|y| (x / x_scale, y / y_scale))),
+                    0.,
+                    rgb,
+                )
+                .border_style(rgb),
+            )?;
+            if let Some(key) = config.legend.as_ref().and_then(|legend| legend.get(k)) {
+                series.label(key).legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 20, y + 5)], rgb.filled())
+                });
+            }
+        }
+        config.on_chart(&mut chart)?;
+        if config
+            .legend
+            .as_ref()
+            .is_some_and(|legend| !legend.is_empty())
+        {
+            config.draw_legend(fig, &mut chart, SeriesLabelPosition::UpperLeft)?;
+        }
+        Ok(())
+    }
+}
+
+/// Plots the stacked areas with the data formated into an iterator
+/// where each item is the tuple `(x[i], vec![y1[i], y2[i], ...])`,
+/// the graph is written in the file `complot-stacked-area.svg`
+impl FromIterator<(f64, Vec<f64>)> for StackedArea {
+    fn from_iter<I: IntoIterator<Item = (f64, Vec<f64>)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-stacked-area.svg")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for StackedArea {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let xy: Vec<_> = iter.collect();
+        let path = config
+            .render::<StackedArea>(&xy, "complot-stacked-area", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in StackedArea: {}", e);
+                String::new()
+            });
+        StackedArea { path }
+    }
+}
@@ -0,0 +1,142 @@
+use super::{draw_line, Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Shaded bands between 2 curves
+///
+/// Fills the region between the lower and upper curves with a translucent color
+/// and draws the center curve halfway between them if [`Config::center_line`] is set
+/// ```
+/// let _: complot::Band = (
+///     (0..100).map(|k| {
+///         let x = k as f64 / 10.;
+///         let (mean, sigma) = (x.sin(), 0.1 + 0.05 * x);
+///         (x, mean - sigma, mean + sigma)
+///     }),
+///     Some(
+///         complot::Config::new()
+///             .filename("band.svg")
+///             .fill_style((255, 0, 0), 0.2)
+///             .center_line(true)
+///             .legend(vec!["mean ± σ"]),
+///     ),
+/// )
+///     .into();
+/// ```
+pub struct Band {
+    path: String,
+}
+impl_path!(Band);
+
+impl Draw for Band {
+    type Data = [(f64, f64, f64)];
+    fn draw<DB>(data: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if data.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        let (x_min, x_max) = data
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), (x, _, _)| {
+                (a.min(*x), b.max(*x))
+            });
+        let (y_min, y_max) = data.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(a, b), (_, lower, upper)| (a.min(*lower).min(*upper), b.max(*lower).max(*upper)),
+        );
+        let xrange = config.xaxis.range.clone().unwrap_or(x_min..x_max);
+        let yrange = config.yaxis.range.clone().unwrap_or(y_min..y_max);
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        )?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = config.xaxis.scaled_label() {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = config.yaxis.scaled_label() {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+
+        let rgb = config.series_color(0);
+        let fill = match config.fill_style {
+            Some(((r, g, b), alpha)) => RGBColor(r, g, b).mix(alpha),
+            None => rgb.mix(0.3),
+        };
+        // the polygon goes along the upper curve and comes back along the lower one
+        let polygon: Vec<_> = data
+            .iter()
+            .map(|(x, _, upper)| (x / x_scale, upper / y_scale))
+            .chain(
+                data.iter()
+                    .rev()
+                    .map(|(x, lower, _)| (x / x_scale, lower / y_scale)),
+            )
+            .collect();
+        let series = chart.draw_series(std::iter::once(Polygon::new(polygon, fill.filled())))?;
+        let key = config
+            .legend
+            .as_ref()
+            .and_then(|legend| legend.first())
+            .map(|key| key.as_str());
+        if let Some(key) = key {
+            series
+                .label(key)
+                .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], fill.filled()));
+        }
+        if config.center_line {
+            draw_line(
+                &mut chart,
+                data.iter()
+                    .map(|(x, lower, upper)| (x / x_scale, 0.5 * (lower + upper) / y_scale)),
+                rgb,
+                None,
+                config,
+            )?;
+        }
+        config.on_chart(&mut chart)?;
+        if key.is_some() {
+            config.draw_legend(fig, &mut chart, SeriesLabelPosition::UpperRight)?;
+        }
+        Ok(())
+    }
+}
+
+/// Plots the band between the lower and upper curves with the data formated into an iterator
+/// where each item is the tuple `(x[i], lower[i], upper[i])`,
+/// the graph is written in the file `complot-band.svg`
+impl FromIterator<(f64, f64, f64)> for Band {
+    fn from_iter<I: IntoIterator<Item = (f64, f64, f64)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-band.svg")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (f64, f64, f64)>> From<(I, Option<Config>)> for Band {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<Band>(&data, "complot-band", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Band: {}", e);
+                String::new()
+            });
+        Band { path }
+    }
+}
@@ -0,0 +1,265 @@
+use super::{min_gap, Config, Draw, Utils};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Direction of the bars of a [`Bar`] chart
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Orientation {
+    /// The bars extend upward from the x-axis
+    #[default]
+    Vertical,
+    /// The bars extend rightward from the y-axis
+    Horizontal,
+}
+
+/// Bar charts
+///
+/// Draws a group of bars (y1, y2, ...) at each x
+/// ```
+/// let _: complot::Bar = (
+///     (1..6).map(|k| (k as f64, vec![k as f64, (k * k) as f64 / 5.])),
+///     Some(
+///         complot::Config::new()
+///             .filename("bar.svg")
+///             .legend(vec!["linear", "quadratic"])
+///             .bar_width(0.6),
+///     ),
+/// )
+///     .into();
+/// ```
+///
+/// With [`Orientation::Horizontal`] the groups are stacked along the y-axis,
+/// [`Config::xaxis`] and [`Config::yaxis`] still set the horizontal and the vertical axes
+/// ```
+/// let _: complot::Bar = (
+///     (1..6).map(|k| (k as f64, vec![k as f64])),
+///     Some(
+///         complot::Config::new()
+///             .filename("horizontal-bar.svg")
+///             .orientation(complot::Orientation::Horizontal)
+///             .xaxis(complot::Axis::new().label("count")),
+///     ),
+/// )
+///     .into();
+/// ```
+pub struct Bar {
+    path: String,
+}
+impl_path!(Bar);
+impl Bar {
+    /// Returns the smallest gap between 2 consecutive x, 1 if there is a single x,
+    /// that sets the width of the groups of bars
+    pub(crate) fn gap(xy: &[(f64, Vec<f64>)]) -> f64 {
+        min_gap(xy.iter().map(|(x, _)| *x))
+    }
+}
+impl Utils for Bar {}
+
+impl Draw for Bar {
+    type Data = [(f64, Vec<f64>)];
+    fn draw<DB>(xy: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if xy.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let horizontal = config.orientation == Orientation::Horizontal;
+        // the axes of the positions of the groups and of the bar values
+        let (x_axis, y_axis) = if horizontal {
+            (&config.yaxis, &config.xaxis)
+        } else {
+            (&config.xaxis, &config.yaxis)
+        };
+        let (x_scale, y_scale) = (x_axis.scale(), y_axis.scale());
+        let (x_max, y_max) = Bar::xy_max(xy);
+        let (x_min, y_min) = Bar::xy_min(xy);
+        let gap = Bar::gap(xy);
+        let n_y = xy[0].1.len();
+        let group_width = gap * config.bar_width;
+        let bar_width = group_width / n_y as f64;
+
+        let xrange = x_axis
+            .range
+            .clone()
+            .unwrap_or(x_min - 0.5 * gap..x_max + 0.5 * gap);
+        let yrange = y_axis.range.clone().unwrap_or(y_min.min(0.)..y_max.max(0.));
+        let (xrange, yrange) = (
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        );
+        // the chart coordinates of the position x and the value y
+        let at = |x: f64, y: f64| if horizontal { (y, x) } else { (x, y) };
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        let (chart_xrange, chart_yrange) = if horizontal {
+            (yrange, xrange)
+        } else {
+            (xrange, yrange)
+        };
+        let mut chart = chart.build_cartesian_2d(chart_xrange, chart_yrange)?;
+        // the categories are written at the integer positions
+        let category = |x: &f64| match &config.categories {
+            Some(categories) if (x - x.round()).abs() < 1e-6 && *x >= 0. => categories
+                .get(x.round() as usize)
+                .cloned()
+                .unwrap_or_default(),
+            _ => String::new(),
+        };
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if horizontal {
+            mesh.disable_y_mesh();
+        } else {
+            mesh.disable_x_mesh();
+        }
+        if let Some(categories) = &config.categories {
+            if horizontal {
+                mesh.y_labels(categories.len()).y_label_formatter(&category);
+            } else {
+                mesh.x_labels(categories.len()).x_label_formatter(&category);
+            }
+        }
+        if let Some(value) = config.xaxis.scaled_label() {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = config.yaxis.scaled_label() {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+
+        let mut legend = config.legend.iter().flatten();
+        for k in 0..n_y {
+            let rgb = config.series_color(k);
+            let series = chart.draw_series(xy.iter().filter_map(|(x, y)| {
+                y.get(k).map(|y| {
+                    let left = x - 0.5 * group_width + k as f64 * bar_width;
+                    Rectangle::new(
+                        [
+                            at(left / x_scale, 0.),
+                            at((left + bar_width) / x_scale, y / y_scale),
+                        ],
+                        rgb.filled(),
+                    )
+                })
+            }))?;
+            if let Some(key) = legend.next() {
+                series.label(key).legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 20, y + 5)], rgb.filled())
+                });
+            }
+        }
+        config.on_chart(&mut chart)?;
+        if config
+            .legend
+            .as_ref()
+            .is_some_and(|legend| !legend.is_empty())
+        {
+            config.draw_legend(
+                fig,
+                &mut chart,
+                if horizontal {
+                    SeriesLabelPosition::LowerRight
+                } else {
+                    SeriesLabelPosition::UpperLeft
+                },
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Plots groups of bars (y1, y2, ...) with the data formated into an iterator
+/// where each item is the tuple `(x[i], vec![y1[i], y2[i], ...])`,
+/// the graph is written in the file `complot-bar.svg`
+/// ```
+/// (0..10)
+///     .map(|k| (k as f64, vec![(k as f64).sqrt()]))
+///     .collect::<complot::Bar>();
+/// ```
+impl FromIterator<(f64, Vec<f64>)> for Bar {
+    fn from_iter<I: IntoIterator<Item = (f64, Vec<f64>)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-bar.svg")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for Bar {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let xy: Vec<_> = iter.collect();
+        let path = config
+            .render::<Bar>(&xy, "complot-bar", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Bar: {}", e);
+                String::new()
+            });
+        Bar { path }
+    }
+}
+
+/// Bar charts of categories
+///
+/// Draws a group of bars (y1, y2, ...) for each category, the categories are evenly spaced
+/// and written in place of the x values
+/// ```
+/// let _: complot::CategoryBar = (
+///     vec![("apples", vec![3., 5.]), ("pears", vec![2., 1.]), ("plums", vec![4., 4.])]
+///         .into_iter()
+///         .map(|(fruit, counts)| (fruit.to_string(), counts)),
+///     Some(
+///         complot::Config::new()
+///             .filename("category-bar.svg")
+///             .legend(vec!["2023", "2024"]),
+///     ),
+/// )
+///     .into();
+/// let svg = std::fs::read_to_string("category-bar.svg").unwrap();
+/// assert!(svg.contains("apples") && svg.contains("plums"));
+/// ```
+pub struct CategoryBar {
+    path: String,
+}
+impl_path!(CategoryBar);
+/// Plots groups of bars (y1, y2, ...) with the data formated into an iterator
+/// where each item is the tuple `(category[i], vec![y1[i], y2[i], ...])`,
+/// the graph is written in the file `complot-category-bar.svg`
+impl FromIterator<(String, Vec<f64>)> for CategoryBar {
+    fn from_iter<I: IntoIterator<Item = (String, Vec<f64>)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-category-bar.svg")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (String, Vec<f64>)>> From<(I, Option<Config>)> for CategoryBar {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let (categories, y): (Vec<_>, Vec<_>) = iter.unzip();
+        let xy: Vec<_> = y
+            .into_iter()
+            .enumerate()
+            .map(|(k, y)| (k as f64, y))
+            .collect();
+        let config = Config {
+            categories: Some(categories),
+            ..config.unwrap_or_default()
+        };
+        let path = config
+            .render::<Bar>(&xy, "complot-category-bar", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in CategoryBar: {}", e);
+                String::new()
+            });
+        CategoryBar { path }
+    }
+}
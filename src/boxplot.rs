@@ -0,0 +1,203 @@
+use super::{min_gap, Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Box-and-whisker plots
+///
+/// Draws for each x the box from the first to the third quartile of the samples with the median inside,
+/// the whiskers extend to the most extreme samples within 1.5 times the interquartile range from the box
+/// and the samples beyond the whiskers are drawn as outliers
+/// ```
+/// let samples = |k: usize| {
+///     (0..100)
+///         .map(|i| k as f64 + ((i * (k + 3)) as f64 * 0.37).sin() * (k + 1) as f64)
+///         .chain(std::iter::once(3. * (k + 2) as f64))
+///         .collect::<Vec<f64>>()
+/// };
+/// let _: complot::BoxPlot = (
+///     (1..5).map(|k| (k as f64, samples(k))),
+///     Some(complot::Config::new().filename("boxplot.svg")),
+/// )
+///     .into();
+/// ```
+pub struct BoxPlot {
+    path: String,
+}
+impl_path!(BoxPlot);
+/// Box-and-whisker statistics of a set of samples
+struct Stats {
+    q1: f64,
+    median: f64,
+    q3: f64,
+    lower_whisker: f64,
+    upper_whisker: f64,
+    outliers: Vec<f64>,
+}
+impl Stats {
+    fn new(samples: &[f64]) -> Option<Self> {
+        let mut samples: Vec<_> = samples.iter().cloned().filter(|x| x.is_finite()).collect();
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_by(f64::total_cmp);
+        let quantile = |q: f64| {
+            let k = q * (samples.len() - 1) as f64;
+            let (i, f) = (k.floor() as usize, k.fract());
+            samples[i] + f * (samples[(i + 1).min(samples.len() - 1)] - samples[i])
+        };
+        let (q1, median, q3) = (quantile(0.25), quantile(0.5), quantile(0.75));
+        let (lower_fence, upper_fence) = (q1 - 1.5 * (q3 - q1), q3 + 1.5 * (q3 - q1));
+        let inliers = || {
+            samples
+                .iter()
+                .cloned()
+                .filter(|x| (lower_fence..=upper_fence).contains(x))
+        };
+        Some(Self {
+            q1,
+            median,
+            q3,
+            lower_whisker: inliers().fold(q1, f64::min),
+            upper_whisker: inliers().fold(q3, f64::max),
+            outliers: samples
+                .iter()
+                .cloned()
+                .filter(|x| !(lower_fence..=upper_fence).contains(x))
+                .collect(),
+        })
+    }
+    /// Returns the range from the lowest to the highest whisker or outlier
+    fn range(&self) -> (f64, f64) {
+        self.outliers
+            .iter()
+            .fold((self.lower_whisker, self.upper_whisker), |(a, b), x| {
+                (a.min(*x), b.max(*x))
+            })
+    }
+}
+
+impl Draw for BoxPlot {
+    type Data = [(f64, Vec<f64>)];
+    fn draw<DB>(data: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let stats: Vec<_> = data
+            .iter()
+            .filter_map(|(x, samples)| Stats::new(samples).map(|stats| (*x, stats)))
+            .collect();
+        if stats.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        let (x_min, x_max) = stats
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), (x, _)| {
+                (a.min(*x), b.max(*x))
+            });
+        let (y_min, y_max) =
+            stats
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), (_, stats)| {
+                    let (y_min, y_max) = stats.range();
+                    (a.min(y_min), b.max(y_max))
+                });
+        // The smallest gap between 2 consecutive x sets the width of the boxes
+        let gap = min_gap(stats.iter().map(|(x, _)| *x));
+        let (y_min, y_max) = if y_max > y_min {
+            (y_min, y_max)
+        } else {
+            (y_min - 0.5, y_max + 0.5)
+        };
+        let xrange = config
+            .xaxis
+            .range
+            .clone()
+            .unwrap_or(x_min - 0.5 * gap..x_max + 0.5 * gap);
+        let yrange = config.yaxis.range.clone().unwrap_or(y_min..y_max);
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        )?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        mesh.disable_x_mesh();
+        if let Some(value) = config.xaxis.scaled_label() {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = config.yaxis.scaled_label() {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+
+        let rgb = config.series_color(0);
+        let half_width = 0.25 * gap / x_scale;
+        for (x, stats) in &stats {
+            let x = x / x_scale;
+            let [q1, median, q3, lower_whisker, upper_whisker] = [
+                stats.q1,
+                stats.median,
+                stats.q3,
+                stats.lower_whisker,
+                stats.upper_whisker,
+            ]
+            .map(|y| y / y_scale);
+            chart.draw_series(vec![
+                Rectangle::new(
+                    [(x - half_width, q1), (x + half_width, q3)],
+                    rgb.mix(0.3).filled(),
+                ),
+                Rectangle::new(
+                    [(x - half_width, q1), (x + half_width, q3)],
+                    rgb.stroke_width(1),
+                ),
+            ])?;
+            chart.draw_series(
+                vec![
+                    vec![(x - half_width, median), (x + half_width, median)],
+                    vec![(x, q1), (x, lower_whisker)],
+                    vec![(x, q3), (x, upper_whisker)],
+                    vec![
+                        (x - 0.5 * half_width, lower_whisker),
+                        (x + 0.5 * half_width, lower_whisker),
+                    ],
+                    vec![
+                        (x - 0.5 * half_width, upper_whisker),
+                        (x + 0.5 * half_width, upper_whisker),
+                    ],
+                ]
+                .into_iter()
+                .map(|points| PathElement::new(points, rgb.stroke_width(2))),
+            )?;
+            chart.draw_series(
+                stats
+                    .outliers
+                    .iter()
+                    .map(|y| Circle::new((x, y / y_scale), 3, rgb)),
+            )?;
+        }
+        config.on_chart(&mut chart)?;
+        Ok(())
+    }
+}
+
+/// Draws the box-and-whisker plots of the samples `(x, samples)`
+impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for BoxPlot {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<BoxPlot>(&data, "complot-boxplot", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in BoxPlot: {}", e);
+                String::new()
+            });
+        BoxPlot { path }
+    }
+}
@@ -0,0 +1,147 @@
+use super::{padded, Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Bubble chart
+///
+/// Each point `(x[i], y[i], size[i])` is drawn as a disk with an area proportional to `size[i]`,
+/// the largest disk has a radius of 20 pixels unless [`Config::bubble_scale`] is set.
+/// The axis ranges are padded for the disks at the edges not to be clipped
+/// ```
+/// let _: complot::Bubble = (
+///     (0..30).map(|k| {
+///         let x = k as f64 / 3.;
+///         (x, x.sin(), 1. + (x * 0.7).cos().abs() * 10.)
+///     }),
+///     Some(complot::Config::new().filename("bubble.svg")),
+/// )
+///     .into();
+/// ```
+pub struct Bubble {
+    path: String,
+}
+impl_path!(Bubble);
+
+impl Draw for Bubble {
+    type Data = [(f64, f64, f64)];
+    fn draw<DB>(data: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if data.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        let size_max = data
+            .iter()
+            .map(|(_, _, size)| *size)
+            .filter(|size| *size > 0.)
+            .fold(0f64, f64::max);
+        // the radius in pixels of a disk of size 1
+        let scale = config.bubble_scale.unwrap_or_else(|| {
+            if size_max > 0. {
+                20. / size_max.sqrt()
+            } else {
+                0.
+            }
+        });
+        let radius_max = scale * size_max.sqrt();
+
+        let (x_min, x_max, y_min, y_max) = data.iter().fold(
+            (
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(a, b, c, d), (x, y, _)| (a.min(*x), b.max(*x), c.min(*y), d.max(*y)),
+        );
+        // the fraction of the range added on each side for the largest disk to fit
+        // in the plotting area laid out by Config::chart_builder
+        let (width, height) = fig.dim_in_pixel();
+        let margin = 2 * config.margin.unwrap_or(10);
+        let (left, bottom) = config.label_area.unwrap_or((50, 40));
+        let padding = |pixels: u32| {
+            let r = radius_max / pixels.saturating_sub(margin).max(1) as f64;
+            if r < 0.5 {
+                r / (1. - 2. * r)
+            } else {
+                1.
+            }
+        };
+        let xrange = config.xaxis.range.clone().unwrap_or_else(|| {
+            padded(
+                x_min..x_max,
+                config.range_padding.unwrap_or(0.) + padding(width.saturating_sub(left)),
+            )
+        });
+        let yrange = config.yaxis.range.clone().unwrap_or_else(|| {
+            padded(
+                y_min..y_max,
+                config.range_padding.unwrap_or(0.) + padding(height.saturating_sub(bottom)),
+            )
+        });
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        )?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = config.xaxis.scaled_label() {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = config.yaxis.scaled_label() {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+        let color = config.series_color(0);
+        // the largest disks are drawn first not to hide the smallest ones
+        let mut bubbles: Vec<_> = data.iter().filter(|(_, _, size)| *size > 0.).collect();
+        bubbles.sort_by(|a, b| b.2.total_cmp(&a.2));
+        for (x, y, size) in bubbles {
+            let point = (x / x_scale, y / y_scale);
+            let radius = ((scale * size.sqrt()).round() as i32).max(1);
+            chart.draw_series([
+                Circle::new(point, radius, color.mix(0.5).filled()),
+                Circle::new(point, radius, color.stroke_width(1)),
+            ])?;
+        }
+        config.draw_reference_lines(&mut chart)?;
+        config.draw_annotations(&mut chart)?;
+        config.on_chart(&mut chart)?;
+        Ok(())
+    }
+}
+
+/// Plots the bubbles formated into an iterator where each item is the tuple `(x[i], y[i], size[i])`,
+/// the graph is written in the file `complot-bubble.svg`
+impl FromIterator<(f64, f64, f64)> for Bubble {
+    fn from_iter<I: IntoIterator<Item = (f64, f64, f64)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-bubble.svg")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (f64, f64, f64)>> From<(I, Option<Config>)> for Bubble {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<Bubble>(&data, "complot-bubble.svg", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Bubble: {}", e);
+                String::new()
+            });
+        Bubble { path }
+    }
+}
@@ -0,0 +1,138 @@
+use super::{normalize, padded, Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Scatter plot colored by a third value
+///
+/// Each point `(x[i], y[i], z[i])` is colored with the colormap at `z[i]`,
+/// the colormap bounds are the range of the `z` values unless [`Config::cmap_minmax`] is set
+/// ```
+/// let _: complot::ColorScatter = (
+///     (0..500).map(|k| {
+///         let o = k as f64 * 0.05;
+///         (o.sqrt() * o.cos(), o.sqrt() * o.sin(), o)
+///     }),
+///     Some(
+///         complot::Config::new()
+///             .filename("color-scatter.png")
+///             .colorbar(complot::Colorbar::new().label("angle")),
+///     ),
+/// )
+///     .into();
+/// ```
+pub struct ColorScatter {
+    path: String,
+}
+impl_path!(ColorScatter);
+
+impl Draw for ColorScatter {
+    type Data = [(f64, f64, f64)];
+    fn draw<DB>(data: &Self::Data, root: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if data.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        let (x_min, x_max, y_min, y_max) = data.iter().fold(
+            (
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(a, b, c, d), (x, y, _)| (a.min(*x), b.max(*x), c.min(*y), d.max(*y)),
+        );
+        let xrange = config
+            .xaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| padded(x_min..x_max, config.range_padding.unwrap_or(0.)));
+        let yrange = config
+            .yaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| padded(y_min..y_max, config.range_padding.unwrap_or(0.)));
+        let z_range = config.cmap_range(data.iter().map(|(_, _, z)| *z))?;
+        let log = config.colorbar_log();
+
+        let (_, height) = root.dim_in_pixel();
+        let (plot, colorbar) = root.split_vertically(height.saturating_sub(80));
+        let mut chart = config.chart_builder(&plot, 10, (50, 40));
+        config.caption(&plot, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        )?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = config.xaxis.scaled_label() {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = config.yaxis.scaled_label() {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(&plot, &chart)?;
+        let size = config.marker_size as i32;
+        chart.draw_series(data.iter().map(|(x, y, z)| {
+            let c = config
+                .cmap
+                .eval_continuous(normalize(*z, &z_range, log).clamp(0., 1.))
+                .as_tuple();
+            Circle::new(
+                (x / x_scale, y / y_scale),
+                size,
+                RGBColor(c.0, c.1, c.2).filled(),
+            )
+        }))?;
+        config.draw_reference_lines(&mut chart)?;
+        config.draw_annotations(&mut chart)?;
+        config.on_chart(&mut chart)?;
+
+        // COLORBAR
+        colorbar.fill(&BLACK)?;
+        config.draw_colorbar(
+            ChartBuilder::on(&colorbar)
+                .margin_left(20)
+                .margin_right(20)
+                .set_label_area_size(LabelAreaPosition::Bottom, 40),
+            z_range.clone(),
+            &z_range,
+            colorbar.dim_in_pixel().0 as usize,
+            config.colorbar.as_ref().and_then(|c| c.label.as_ref()),
+            false,
+        )?;
+        Ok(())
+    }
+}
+
+/// Plots the points formated into an iterator where each item is the tuple `(x[i], y[i], z[i])`,
+/// the graph is written in the file `complot-color-scatter.png`
+impl FromIterator<(f64, f64, f64)> for ColorScatter {
+    fn from_iter<I: IntoIterator<Item = (f64, f64, f64)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-color-scatter.png")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (f64, f64, f64)>> From<(I, Option<Config>)> for ColorScatter {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<ColorScatter>(&data, "complot-color-scatter.png", (768, 848))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in ColorScatter: {}", e);
+                String::new()
+            });
+        ColorScatter { path }
+    }
+}
@@ -1,185 +1,343 @@
-use super::{canvas, Config, Utils};
-use colorous;
-use plotters::prelude::*;
+use super::{draw_line, draw_markers, Bar, Config, Draw, LineStyle, Utils};
+use plotters::{coord::Shift, prelude::*};
+
+type Result<T> = std::result::Result<T, crate::Error>;
 
 /// Combines plots of different [`Kind`]s
-pub struct Combo {}
+///
+/// The axis ranges that are not set in [`Config`] are derived from all the data with [`Config::auto_range`]
+/// ```
+/// use complot::{Combo, Config, Kind};
+/// let line = (0..100).map(|k| {
+///     let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+///     (o, vec![o.sin()])
+/// });
+/// let dots = (0..20).map(|k| {
+///     let o = 5. * std::f64::consts::PI * k as f64 / 20.;
+///     (o, vec![o.cos()])
+/// });
+/// let _: Combo = From::<complot::Complot>::from((
+///     vec![Box::new(line), Box::new(dots)],
+///     vec![Kind::Plot(None), Kind::Scatter(None)],
+///     Some(Config::new().filename("combo.svg")),
+/// ));
+/// ```
+///
+/// A [`Kind::DashedPlot`] draws dashed lines, visually distinct from the data lines
+/// ```
+/// use complot::{Combo, Config, Kind};
+/// let target = (0..100).map(|k| (k as f64, vec![k as f64 / 100.]));
+/// let data = (0..100).map(|k| (k as f64, vec![k as f64 / 100. + 0.05 * (k as f64 / 5.).sin()]));
+/// let _: Combo = From::<complot::Complot>::from((
+///     vec![Box::new(data), Box::new(target)],
+///     vec![
+///         Kind::Plot(Some("data".to_string())),
+///         Kind::DashedPlot(Some("target".to_string())),
+///     ],
+///     Some(Config::new().filename("combo-dashed.png")),
+/// ));
+/// ```
+///
+/// A [`Kind::Bar`] draws bars with a width derived from the x spacing, e.g. to overlay a fit on histogram counts
+/// ```
+/// use complot::{Combo, Config, Kind};
+/// let counts = (-5..=5).map(|k| {
+///     let x = k as f64;
+///     (x, vec![(100. * (-x * x / 8.).exp()).round()])
+/// });
+/// let fit = (-50..=50).map(|k| {
+///     let x = k as f64 / 10.;
+///     (x, vec![100. * (-x * x / 8.).exp()])
+/// });
+/// let _: Combo = From::<complot::Complot>::from((
+///     vec![Box::new(counts), Box::new(fit)],
+///     vec![
+///         Kind::Bar(Some("counts".to_string())),
+///         Kind::Plot(Some("fit".to_string())),
+///     ],
+///     Some(Config::new().filename("combo-bar.png")),
+/// ));
+/// ```
+///
+/// The series can also be added one by one with [`Combo::builder`]
+/// ```
+/// use complot::{Combo, Config};
+/// let _: Combo = Combo::builder(Some(Config::new().filename("combo-builder.png")))
+///     .plot((0..100).map(|k| (k as f64, vec![(k as f64 / 10.).sin()])), Some("sin"))
+///     .scatter((0..20).map(|k| (5. * k as f64, vec![(k as f64 / 2.).cos()])), Some("cos"))
+///     .build();
+/// assert!(std::path::Path::new("combo-builder.png").exists());
+/// // without filename, the graph is written in complot-plot.svg
+/// let combo: Combo = Combo::builder(None)
+///     .plot((0..100).map(|k| (k as f64, vec![(k as f64 / 10.).cos()])), None)
+///     .build();
+/// assert_eq!(combo.path(), "complot-plot.svg");
+/// ```
+pub struct Combo {
+    path: String,
+}
+impl_path!(Combo);
 impl Utils for Combo {}
+impl Combo {
+    /// Returns a [`ComboBuilder`] to add the series of a [`Combo`] one by one
+    pub fn builder(config: Option<Config>) -> ComboBuilder {
+        ComboBuilder {
+            iters: Vec::new(),
+            draws: Vec::new(),
+            config,
+        }
+    }
+}
+
+/// Builder of a [`Combo`] accumulating the series and their [`Kind`]s
+pub struct ComboBuilder {
+    iters: Vec<Box<dyn Iterator<Item = (f64, Vec<f64>)>>>,
+    draws: Vec<Kind>,
+    config: Option<Config>,
+}
+impl ComboBuilder {
+    /// Adds the series `iter` drawn as `kind`
+    pub fn series<I>(mut self, iter: I, kind: Kind) -> Self
+    where
+        I: Iterator<Item = (f64, Vec<f64>)> + 'static,
+    {
+        self.iters.push(Box::new(iter));
+        self.draws.push(kind);
+        self
+    }
+    /// Adds the lines of `iter` with an optional legend `label`, see [`Kind::Plot`]
+    pub fn plot<I>(self, iter: I, label: Option<&str>) -> Self
+    where
+        I: Iterator<Item = (f64, Vec<f64>)> + 'static,
+    {
+        self.series(iter, Kind::Plot(label.map(|label| label.to_string())))
+    }
+    /// Adds the dashed lines of `iter` with an optional legend `label`, see [`Kind::DashedPlot`]
+    pub fn dashed_plot<I>(self, iter: I, label: Option<&str>) -> Self
+    where
+        I: Iterator<Item = (f64, Vec<f64>)> + 'static,
+    {
+        self.series(iter, Kind::DashedPlot(label.map(|label| label.to_string())))
+    }
+    /// Adds the markers of `iter` with an optional legend `label`, see [`Kind::Scatter`]
+    pub fn scatter<I>(self, iter: I, label: Option<&str>) -> Self
+    where
+        I: Iterator<Item = (f64, Vec<f64>)> + 'static,
+    {
+        self.series(iter, Kind::Scatter(label.map(|label| label.to_string())))
+    }
+    /// Adds the bars of `iter` with an optional legend `label`, see [`Kind::Bar`]
+    pub fn bar<I>(self, iter: I, label: Option<&str>) -> Self
+    where
+        I: Iterator<Item = (f64, Vec<f64>)> + 'static,
+    {
+        self.series(iter, Kind::Bar(label.map(|label| label.to_string())))
+    }
+    /// Draws the series and writes the graph
+    pub fn build(self) -> Combo {
+        (self.iters, self.draws, self.config).into()
+    }
+}
 #[derive(Clone)]
 pub enum Kind {
     Plot(Option<String>),
     Scatter(Option<String>),
+    /// Dashed lines, e.g. for target or reference trajectories
+    DashedPlot(Option<String>),
+    /// Bars from 0 to each y, side by side for several y at the same x,
+    /// the width of the bars derives from the smallest gap between x and [`Config::bar_width`]
+    Bar(Option<String>),
 }
 pub type Complot = (
-    Vec<Box<(dyn Iterator<Item = (f64, Vec<f64>)>)>>,
+    Vec<Box<dyn Iterator<Item = (f64, Vec<f64>)>>>,
     Vec<Kind>,
     Option<Config>,
 );
-impl From<Complot> for Combo {
-    fn from((iters, draws, config): Complot) -> Self {
-        let config = config.unwrap_or_default();
-        let filename = config
-            .filename
-            .unwrap_or_else(|| "complot-plot.svg".to_string());
-
-        let fig = canvas(&filename, (768, 512)); //SVGBackend::new(&filename, (768, 512)).into_drawing_area();
-                                                 //let fig = SVGBackend::new(&filename, (768, 512)).into_drawing_area();
-        fig.fill(&WHITE).unwrap();
-        /*
-                let (xrange, yrange) = match (config.xaxis.range, config.yaxis.range) {
-                    (Some(xrange), Some(yrange)) => (xrange, yrange),
-                    (Some(xrange), None) => {
-                        let mut yrange = f64::INFINITY..f64::NEG_INFINITY;
-                        for iter in &iters {
-                            let xy: Vec<_> = (&**iter).collect();
-                            let (_, it_yrange) = Self::xy_range(&xy);
-                            yrange.start = yrange.start.min(it_yrange.start);
-                            yrange.end = yrange.end.max(it_yrange.end);
-                        }
-                        (xrange, yrange)
-                    }
-                    (None, Some(yrange)) => {
-                        let mut xrange = f64::INFINITY..f64::NEG_INFINITY;
-                        for iter in &iters {
-                            let xy: Vec<_> = (&**iter).collect();
-                            let (it_xrange, _) = Self::xy_range(&xy);
-                            xrange.start = xrange.start.min(it_xrange.start);
-                            xrange.end = xrange.end.max(it_xrange.end);
-                        }
-                        (xrange, yrange)
-                    }
-                    (None, None) => {
-                        let mut xrange = f64::INFINITY..f64::NEG_INFINITY;
-                        let mut yrange = f64::INFINITY..f64::NEG_INFINITY;
-                        for iter in &iters {
-                            let xy: Vec<_> = (&**iter).collect();
-                            let (it_xrange, it_yrange) = Self::xy_range(&xy);
-                            xrange.start = xrange.start.min(it_xrange.start);
-                            xrange.end = xrange.end.max(it_xrange.end);
-                            yrange.start = yrange.start.min(it_yrange.start);
-                            yrange.end = yrange.end.max(it_yrange.end);
-                        }
-                        (xrange, yrange)
-                    }
-                };
-        */
-        let (xrange, yrange) = (config.xaxis.range.unwrap(), config.yaxis.range.unwrap());
+impl Draw for Combo {
+    type Data = [(Vec<(f64, Vec<f64>)>, Kind)];
+    fn draw<DB>(data: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let mut config = config.clone();
+        if config.legend.is_none() {
+            // the labels of the series in the order of the colors, for the legend drawn outside
+            config.legend = Some(
+                data.iter()
+                    .flat_map(|(xy, draw)| {
+                        let label = match draw {
+                            Kind::Plot(label)
+                            | Kind::Scatter(label)
+                            | Kind::DashedPlot(label)
+                            | Kind::Bar(label) => label.clone().unwrap_or_default(),
+                        };
+                        let n_y = xy.first().map_or(0, |(_, y)| y.len());
+                        std::iter::repeat_n(label, n_y)
+                    })
+                    .collect(),
+            );
+        }
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        // the bars start at 0 and extend half a gap beyond the first and last x
+        let bar_edges: Vec<Vec<_>> = data
+            .iter()
+            .filter(|(xy, draw)| matches!(draw, Kind::Bar(_)) && !xy.is_empty())
+            .map(|(xy, _)| {
+                let half_gap = 0.5 * Bar::gap(xy);
+                let (x_min, x_max) = xy
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), (x, _)| {
+                        (a.min(*x), b.max(*x))
+                    });
+                vec![(x_min - half_gap, vec![0.]), (x_max + half_gap, vec![0.])]
+            })
+            .collect();
+        config.auto_range(
+            data.iter()
+                .map(|(xy, _)| xy)
+                .chain(&bar_edges)
+                .map(|xy| xy.as_slice())
+                .collect(),
+        );
+        let (xrange, yrange) = (
+            config.xaxis.range.clone().unwrap_or(0f64..1f64),
+            config.yaxis.range.clone().unwrap_or(0f64..1f64),
+        );
 
-        let mut chart = ChartBuilder::on(&fig)
-            .set_label_area_size(LabelAreaPosition::Left, 50)
-            .set_label_area_size(LabelAreaPosition::Bottom, 40)
-            .margin(10)
-            .build_cartesian_2d(xrange, yrange)
-            .unwrap();
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        )?;
         let mut mesh = chart.configure_mesh();
-        if let Some(value) = config.xaxis.label {
+        config.mesh_style(&mut mesh);
+        if let Some(value) = config.xaxis.scaled_label() {
             mesh.x_desc(value);
         }
-        if let Some(value) = config.yaxis.label {
+        if let Some(value) = config.yaxis.scaled_label() {
             mesh.y_desc(value);
         }
-        mesh.draw().unwrap();
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
 
-        let mut colors = colorous::TABLEAU10.iter().cycle();
+        let mut colors = config.color_cycle();
         let mut legend = false;
-        for (iter, draw) in iters.into_iter().zip(draws.into_iter()) {
-            let xy: Vec<_> = iter.collect();
-            let n_y = xy[0].1.len();
+        for (xy, draw) in data {
+            let n_y = xy.first().map_or(0, |(_, y)| y.len());
+            let gap = Bar::gap(xy) / x_scale;
             let data: Vec<_> = xy
-                .into_iter()
-                .flat_map(|(x, y)| y.into_iter().map(|y| (x, y)).collect::<Vec<(f64, f64)>>())
+                .iter()
+                .flat_map(|(x, y)| {
+                    y.iter()
+                        .map(|y| (x / x_scale, y / y_scale))
+                        .collect::<Vec<(f64, f64)>>()
+                })
                 .collect();
             match draw {
                 Kind::Scatter(None) => {
-                    for k in 0..n_y {
-                        let this_color = colors.next().unwrap().as_tuple();
-                        chart
-                            .draw_series(data.iter().skip(k).step_by(n_y).cloned().map(|point| {
-                                Circle::new(
-                                    point,
-                                    3,
-                                    RGBColor(this_color.0, this_color.1, this_color.2).filled(),
-                                )
-                            }))
-                            .unwrap();
+                    for (k, rgb) in colors.by_ref().take(n_y).enumerate() {
+                        draw_markers(
+                            &mut chart,
+                            data.iter().skip(k).step_by(n_y).cloned(),
+                            rgb.filled(),
+                            &config,
+                        )?;
                     }
                 }
-                Kind::Plot(None) => {
-                    for k in 0..n_y {
-                        let this_color = colors.next().unwrap().as_tuple();
-                        chart
-                            .draw_series(LineSeries::new(
-                                data.iter().skip(k).step_by(n_y).cloned(),
-                                RGBColor(this_color.0, this_color.1, this_color.2),
-                            ))
-                            .unwrap();
+                Kind::Plot(label) => {
+                    legend |= label.is_some();
+                    for (k, rgb) in colors.by_ref().take(n_y).enumerate() {
+                        draw_line(
+                            &mut chart,
+                            data.iter().skip(k).step_by(n_y).cloned(),
+                            rgb,
+                            label.as_deref(),
+                            &config,
+                        )?;
                     }
                 }
-                Kind::Plot(Some(label)) => {
-                    legend = true;
-                    for k in 0..n_y {
-                        let this_color = colors.next().unwrap().as_tuple();
-                        let rgb = RGBColor(
-                            this_color.clone().0,
-                            this_color.clone().1,
-                            this_color.clone().2,
-                        );
-                        let leg_rgb = RGBColor(
-                            this_color.clone().0,
-                            this_color.clone().1,
-                            this_color.clone().2,
-                        );
-                        chart
-                            .draw_series(LineSeries::new(
-                                data.iter().skip(k).step_by(n_y).cloned(),
-                                rgb,
-                            ))
-                            .unwrap()
-                            .label(label.clone())
-                            .legend(move |(x, y)| {
-                                PathElement::new(vec![(x, y), (x + 20, y)], leg_rgb)
+                Kind::DashedPlot(label) => {
+                    legend |= label.is_some();
+                    let dashed = Config {
+                        line_style: LineStyle::Dashed,
+                        ..config.clone()
+                    };
+                    for (k, rgb) in colors.by_ref().take(n_y).enumerate() {
+                        draw_line(
+                            &mut chart,
+                            data.iter().skip(k).step_by(n_y).cloned(),
+                            rgb,
+                            label.as_deref(),
+                            &dashed,
+                        )?;
+                    }
+                }
+                Kind::Bar(label) => {
+                    legend |= label.is_some();
+                    let group_width = gap * config.bar_width;
+                    let width = group_width / n_y as f64;
+                    for (k, rgb) in colors.by_ref().take(n_y).enumerate() {
+                        let offset = -0.5 * group_width + k as f64 * width;
+                        let series = chart.draw_series(data.iter().skip(k).step_by(n_y).map(
+                            |&(x, y)| {
+                                Rectangle::new(
+                                    [(x + offset, 0.), (x + offset + width, y)],
+                                    rgb.filled(),
+                                )
+                            },
+                        ))?;
+                        if let Some(label) = label {
+                            series.label(label.clone()).legend(move |(x, y)| {
+                                Rectangle::new([(x, y - 5), (x + 20, y + 5)], rgb.filled())
                             });
+                        }
                     }
                 }
                 Kind::Scatter(Some(label)) => {
                     legend = true;
-                    for k in 0..n_y {
-                        let this_color = colors.next().unwrap().as_tuple();
-                        let rgb = RGBColor(
-                            this_color.clone().0,
-                            this_color.clone().1,
-                            this_color.clone().2,
-                        );
-                        let leg_rgb = RGBColor(
-                            this_color.clone().0,
-                            this_color.clone().1,
-                            this_color.clone().2,
-                        );
-                        chart
-                            .draw_series(
-                                data.iter()
-                                    .skip(k)
-                                    .step_by(n_y)
-                                    .cloned()
-                                    .map(|point| Circle::new(point, 3, rgb)),
-                            )
-                            .unwrap()
-                            .label(label.clone())
-                            .legend(move |(x, y)| {
-                                PathElement::new(vec![(x, y), (x + 20, y)], leg_rgb)
-                            });
+                    for (k, rgb) in colors.by_ref().take(n_y).enumerate() {
+                        let leg_rgb = rgb;
+                        draw_markers(
+                            &mut chart,
+                            data.iter().skip(k).step_by(n_y).cloned(),
+                            rgb.into(),
+                            &config,
+                        )?
+                        .label(label.clone())
+                        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], leg_rgb));
                     }
                 }
             }
         }
+        if config.draw_reference_lines(&mut chart)? {
+            legend = true;
+        }
+        config.draw_annotations(&mut chart)?;
+        config.on_chart(&mut chart)?;
         if legend {
-            chart
-                .configure_series_labels()
-                .border_style(&BLACK)
-                .background_style(&WHITE.mix(0.8))
-                .draw()
-                .unwrap();
+            // plotters default position, where the Combo legend has always been drawn
+            config.draw_legend(fig, &mut chart, SeriesLabelPosition::MiddleRight)?;
         }
-        Combo {}
+        Ok(())
+    }
+}
+
+impl From<Complot> for Combo {
+    fn from((iters, draws, config): Complot) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iters
+            .into_iter()
+            .map(|iter| iter.collect())
+            .zip(draws)
+            .collect();
+        let path = config
+            .render::<Combo>(&data, "complot-plot.svg", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Combo: {}", e);
+                String::new()
+            });
+        Combo { path }
     }
 }
@@ -0,0 +1,103 @@
+use super::{Axis, Config, Heatmap};
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Confusion matrix
+///
+/// The matrix rows are the true classes and the columns the predicted classes,
+/// the counts are written in the cells and the classes labels are written on both axes,
+/// the counts of each row are divided by the row total if [`Config::normalize_rows`] is set
+/// ```
+/// let matrix = [[50, 2, 3], [5, 40, 5], [0, 8, 42]];
+/// let labels = ["cat", "dog", "bird"];
+/// let _ = complot::ConfusionMatrix::new(
+///     &matrix,
+///     &labels,
+///     Some(complot::Config::new().filename("confusion-matrix.png")),
+/// );
+/// let _ = complot::ConfusionMatrix::new(
+///     &matrix,
+///     &labels,
+///     Some(
+///         complot::Config::new()
+///             .filename("normalized-confusion-matrix.png")
+///             .normalize_rows(true),
+///     ),
+/// );
+/// ```
+pub struct ConfusionMatrix {
+    path: String,
+}
+impl_path!(ConfusionMatrix);
+impl ConfusionMatrix {
+    /// Draws the confusion `matrix` of the classes `labels`,
+    /// the graph is written in the file `complot-confusion-matrix.png` unless a filename is set in `config`
+    pub fn new<R, S>(matrix: &[R], labels: &[S], config: Option<Config>) -> Self
+    where
+        R: AsRef<[usize]>,
+        S: AsRef<str>,
+    {
+        let path = ConfusionMatrix::render(matrix, labels, config.unwrap_or_default())
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in ConfusionMatrix: {}", e);
+                String::new()
+            });
+        ConfusionMatrix { path }
+    }
+    fn render<R, S>(matrix: &[R], labels: &[S], config: Config) -> Result<String>
+    where
+        R: AsRef<[usize]>,
+        S: AsRef<str>,
+    {
+        let n = matrix.len();
+        if let Some((row, got)) = matrix
+            .iter()
+            .map(|row| row.as_ref().len())
+            .enumerate()
+            .find(|(_, len)| *len != n)
+        {
+            return Err(crate::Error::InconsistentSeriesLength {
+                row,
+                expected: n,
+                got,
+            });
+        }
+        let normalize = config.normalize_rows;
+        let data: Vec<f64> = matrix
+            .iter()
+            .flat_map(|row| {
+                let row = row.as_ref();
+                let total: usize = row.iter().sum();
+                row.iter().map(move |&count| {
+                    if normalize && total > 0 {
+                        count as f64 / total as f64
+                    } else {
+                        count as f64
+                    }
+                })
+            })
+            .collect();
+        // the cell centers are at the integer coordinates where the labels are written
+        let range = -0.5..n as f64 - 0.5;
+        let axis = |axis: &Axis, label: &str| Axis {
+            label: axis.label.clone().or_else(|| Some(label.to_string())),
+            range: Some(range.clone()),
+            ..axis.clone()
+        };
+        let config = Config {
+            categories: Some(
+                labels
+                    .iter()
+                    .map(|label| label.as_ref().to_string())
+                    .collect(),
+            ),
+            xaxis: axis(&config.xaxis, "Predicted"),
+            yaxis: axis(&config.yaxis, "True"),
+            cell_labels: true,
+            osf: config.osf.max(400 / n.max(1)),
+            ..config
+        };
+        let (data, size) = Heatmap::collect((data.as_slice(), (n, n)), &config)?;
+        config.render::<Heatmap>(&data, "complot-confusion-matrix.png", size)
+    }
+}
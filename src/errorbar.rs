@@ -0,0 +1,160 @@
+use super::{min_gap, Config, Draw, Scale};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Error bar plots
+///
+/// Draws each point `y` with a vertical whisker from `y-yerr` to `y+yerr`,
+/// the x range extends by half the smallest gap between 2 consecutive x beyond the first and last x
+/// ```
+/// let _: complot::ErrorBar = (
+///     (0..20).map(|k| {
+///         let o = 5. * std::f64::consts::PI * k as f64 / 20.;
+///         (o, vec![(o.sin(), 0.1), (o.cos(), 0.2)])
+///     }),
+///     Some(complot::Config::new().filename("errorbar.svg")),
+/// )
+///     .into();
+/// // a single point and rows with different numbers of series
+/// let errorbar: complot::ErrorBar = (
+///     vec![(1., vec![(1., 0.)]), (2., vec![(2., 0.5), (3., 0.5)])].into_iter(),
+///     Some(complot::Config::new().filename("errorbar-ragged.svg")),
+/// )
+///     .into();
+/// assert_eq!(errorbar.path(), "errorbar-ragged.svg");
+/// let errorbar: complot::ErrorBar = (
+///     std::iter::once((1., vec![(1., 0.)])),
+///     Some(complot::Config::new().filename("errorbar-single.svg")),
+/// )
+///     .into();
+/// assert_eq!(errorbar.path(), "errorbar-single.svg");
+/// ```
+pub struct ErrorBar {
+    path: String,
+}
+impl_path!(ErrorBar);
+
+impl ErrorBar {
+    /// Returns the x and the whiskers y ranges
+    fn ranges(data: &[(f64, Vec<(f64, f64)>)]) -> ((f64, f64), (f64, f64)) {
+        data.iter().fold(
+            (
+                (f64::INFINITY, f64::NEG_INFINITY),
+                (f64::INFINITY, f64::NEG_INFINITY),
+            ),
+            |((x_min, x_max), (y_min, y_max)), (x, y)| {
+                let (y_min, y_max) = y.iter().fold((y_min, y_max), |(y_min, y_max), (y, e)| {
+                    (y_min.min(y - e.abs()), y_max.max(y + e.abs()))
+                });
+                ((x_min.min(*x), x_max.max(*x)), (y_min, y_max))
+            },
+        )
+    }
+}
+
+impl Draw for ErrorBar {
+    type Data = [(f64, Vec<(f64, f64)>)];
+    fn draw<DB>(data: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if data.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        let ((x_min, x_max), (y_min, y_max)) = ErrorBar::ranges(data);
+        // the end caps are kept inside the chart by half the smallest gap between 2 consecutive x
+        let half_gap = 0.5 * min_gap(data.iter().map(|(x, _)| *x));
+        let xrange = config
+            .xaxis
+            .range
+            .clone()
+            .unwrap_or(x_min - half_gap..x_max + half_gap);
+        let yrange = config
+            .yaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| config.pad_range(y_min..y_max, Scale::Linear));
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        )?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = config.xaxis.scaled_label() {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = config.yaxis.scaled_label() {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+
+        // the rows may hold different numbers of series
+        let n_y = data.iter().map(|(_, y)| y.len()).max().unwrap_or(0);
+        let mut legend = config.legend.iter().flatten();
+        for k in 0..n_y {
+            let rgb = config.series_color(k);
+            let series = chart.draw_series(data.iter().filter_map(|(x, y)| {
+                y.get(k).map(|&(y, e)| {
+                    let (x, y, e) = (x / x_scale, y / y_scale, e.abs() / y_scale);
+                    plotters::element::ErrorBar::new_vertical(x, y - e, y, y + e, rgb.filled(), 6)
+                })
+            }))?;
+            if let Some(key) = legend.next() {
+                series
+                    .label(key)
+                    .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], rgb));
+            }
+        }
+        config.on_chart(&mut chart)?;
+        if config
+            .legend
+            .as_ref()
+            .is_some_and(|legend| !legend.is_empty())
+        {
+            config.draw_legend(fig, &mut chart, SeriesLabelPosition::UpperRight)?;
+        }
+        Ok(())
+    }
+}
+
+/// Plots the points `(x, y, yerr)` with error bars
+/// ```
+/// (0..20)
+///     .map(|k| {
+///         let o = 5. * std::f64::consts::PI * k as f64 / 20.;
+///         (o, o.sin(), 0.1)
+///     })
+///     .collect::<complot::ErrorBar>();
+/// ```
+impl FromIterator<(f64, f64, f64)> for ErrorBar {
+    fn from_iter<I: IntoIterator<Item = (f64, f64, f64)>>(iter: I) -> Self {
+        (
+            iter.into_iter().map(|(x, y, e)| (x, vec![(y, e)])),
+            Some(Config::new().filename("complot-errorbar.svg")),
+        )
+            .into()
+    }
+}
+
+/// Plots the series `(x, vec![(y1, yerr1), (y2, yerr2), ...])` with error bars
+impl<I: Iterator<Item = (f64, Vec<(f64, f64)>)>> From<(I, Option<Config>)> for ErrorBar {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<ErrorBar>(&data, "complot-errorbar", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in ErrorBar: {}", e);
+                String::new()
+            });
+        ErrorBar { path }
+    }
+}
@@ -0,0 +1,116 @@
+use super::{Config, Draw, Heatmap, Plot, Scatter};
+use num_traits::{cast::AsPrimitive, Float};
+use plotters::{coord::Shift, prelude::*};
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// A graph in a cell of a [`Grid`] with its own configuration
+enum Cell {
+    Plot(Vec<(f64, Vec<f64>)>, Config),
+    Scatter(Vec<(f64, Vec<f64>)>, Config),
+    Heatmap(<Heatmap as Draw>::Data, Config),
+}
+
+/// Grid of graphs in a single figure
+///
+/// The graphs are added row by row with their own [`Config`], the filenames of which are ignored,
+/// and the figure is written with [`Grid::save`]
+/// ```
+/// fn main() -> complot::Result<()> {
+///     let wave = |f: f64| (0..100).map(move |k| (k as f64, vec![(f * k as f64 / 100.).sin()]));
+///     let data: Vec<f64> = (0..400).map(|k| (k as f64 / 20.).sin()).collect();
+///     let path = complot::Grid::new(2, 2)
+///         .plot(wave(5.), Some(complot::Config::new().title("slow")))
+///         .plot(wave(20.), Some(complot::Config::new().title("fast")))
+///         .scatter(wave(10.), None)
+///         .heatmap((data.as_slice(), (20, 20)), None)
+///         .save(Some(complot::Config::new().filename("grid.svg")))?;
+///     assert_eq!(path, "grid.svg");
+///     Ok(())
+/// }
+/// ```
+pub struct Grid {
+    rows: usize,
+    cols: usize,
+    cells: Vec<Cell>,
+    error: Option<crate::Error>,
+}
+impl Grid {
+    /// Creates a grid of `rows` by `cols` graphs
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows: rows.max(1),
+            cols: cols.max(1),
+            cells: Vec::new(),
+            error: None,
+        }
+    }
+    /// Adds a [`Plot`] to the next cell
+    pub fn plot<I>(mut self, iter: I, config: Option<Config>) -> Self
+    where
+        I: Iterator<Item = (f64, Vec<f64>)>,
+    {
+        let config = config.unwrap_or_default();
+        match Plot::collect(iter, &config) {
+            Ok(xy) => self.cells.push(Cell::Plot(xy, config)),
+            Err(e) => self.error = self.error.or(Some(e)),
+        }
+        self
+    }
+    /// Adds a [`Scatter`] plot to the next cell
+    pub fn scatter<I>(mut self, iter: I, config: Option<Config>) -> Self
+    where
+        I: Iterator<Item = (f64, Vec<f64>)>,
+    {
+        self.cells
+            .push(Cell::Scatter(iter.collect(), config.unwrap_or_default()));
+        self
+    }
+    /// Adds a [`Heatmap`] of the data `(data, (rows, columns))` in row-major order to the next cell
+    pub fn heatmap<T>(mut self, data: (&[T], (usize, usize)), config: Option<Config>) -> Self
+    where
+        T: Float + AsPrimitive<f64>,
+    {
+        let config = config.unwrap_or_default();
+        match Heatmap::collect(data, &config) {
+            Ok((data, _)) => self.cells.push(Cell::Heatmap(data, config)),
+            Err(e) => self.error = self.error.or(Some(e)),
+        }
+        self
+    }
+    /// Draws the graphs and writes the figure in the file given by `config`, `complot-grid.svg` by default
+    ///
+    /// The default figure size is 512x384 pixels per cell,
+    /// the graphs in excess of the number of cells are not drawn
+    ///
+    /// Returns the path of the written file
+    pub fn save(self, config: Option<Config>) -> Result<String> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        let config = config.unwrap_or_default();
+        let size = (512 * self.cols as u32, 384 * self.rows as u32);
+        config.render::<Grid>(&self, "complot-grid.svg", size)
+    }
+}
+impl Draw for Grid {
+    type Data = Grid;
+    fn draw<DB>(grid: &Self::Data, root: &DrawingArea<DB, Shift>, _: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        for (area, cell) in root
+            .split_evenly((grid.rows, grid.cols))
+            .iter()
+            .zip(&grid.cells)
+        {
+            match cell {
+                Cell::Plot(data, config) => Plot::draw(data, area, config)?,
+                Cell::Scatter(data, config) => Scatter::draw(data, area, config)?,
+                Cell::Heatmap(data, config) => Heatmap::draw(data, area, config)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,10 +1,39 @@
-use super::Config;
+use super::{normalize, Colorbar, Config, Draw};
 use num_traits::{cast::AsPrimitive, Float};
-use plotters::prelude::*;
+use plotters::{
+    coord::Shift,
+    prelude::*,
+    style::text_anchor::{HPos, Pos, VPos},
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::io::Write;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+type Result<T> = std::result::Result<T, crate::Error>;
 type Data<'a, T> = (&'a [T], (usize, usize));
 
+/// Position of the first row and column of a [`Heatmap`]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Origin {
+    /// The first row is at the top, like images
+    #[default]
+    TopLeft,
+    /// The first row is at the bottom, like the y-axis of a chart
+    BottomLeft,
+}
+
+/// Position of the colorbar of a [`Heatmap`]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum ColorbarPosition {
+    /// A horizontal colorbar below the heatmap
+    #[default]
+    Bottom,
+    /// A vertical colorbar on the right of the heatmap
+    Right,
+    /// No colorbar, the heatmap takes the whole graph
+    Hidden,
+}
+
 /// Heatmap chart
 ///
 /// ```
@@ -24,100 +53,364 @@ type Data<'a, T> = (&'a [T], (usize, usize));
 ///     .collect::<Vec<f64>>();
 /// let _: complot::Heatmap = ((data.as_slice(), (n, n)), None).into();
 /// ```
-pub struct Heatmap {}
-impl<'a, T: Float + AsPrimitive<f64>> From<(Data<'a, T>, Option<Config>)> for Heatmap {
-    fn from((data, config): (Data<T>, Option<Config>)) -> Self {
-        fn inner<T>((data, config): (Data<T>, Option<Config>)) -> Result<()>
-        where
-            T: Float + AsPrimitive<f64>,
-        {
-            let (map, (rows, cols)) = data;
-            assert_eq!(rows, cols, "rectangular heatmap unimplemented");
-
-            let config = config.unwrap_or_default();
-            let osf = config.osf;
-            let res = rows;
-            let size = res * osf;
-            let filename = config
-                .filename
-                .unwrap_or_else(|| "complot-heatmap.png".to_string());
-            let cmap = config.cmap;
+///
+/// The data is given in row-major order as `(data, (rows, columns))`, grids may be rectangular,
+/// the first row is drawn at the top unless [`Config::origin`] is set to [`Origin::BottomLeft`],
+/// the NaN cells are painted with [`Config::nan_color`]
+/// ```
+/// let (rows, cols) = (50, 200);
+/// let data: Vec<f64> = (0..rows * cols)
+///     .map(|k| ((k % cols) as f64 / 10.).sin() * ((k / cols) as f64 / 10.).cos())
+///     .collect();
+/// let _: complot::Heatmap = (
+///     (data.as_slice(), (rows, cols)),
+///     Some(complot::Config::new().filename("rectangular-heatmap.png")),
+/// )
+///     .into();
+/// ```
+///
+/// The cells span the x and y axis ranges if any is set, the axes are then drawn with their labels
+/// ```
+/// let (rows, cols) = (60, 150);
+/// let data: Vec<f64> = (0..rows * cols)
+///     .map(|k| (-(((k % cols) as f64 - 75.) / 30.).powi(2)).exp() * (k / cols) as f64)
+///     .collect();
+/// let _: complot::Heatmap = (
+///     (data.as_slice(), (rows, cols)),
+///     Some(
+///         complot::Config::new()
+///             .filename("coordinates-heatmap.png")
+///             .origin(complot::Origin::BottomLeft)
+///             .xaxis(complot::Axis::new().label("Wavelength [nm]").range(400.0..700.0))
+///             .yaxis(complot::Axis::new().label("Time [s]").range(0.0..6.0)),
+///     ),
+/// )
+///     .into();
+/// ```
+///
+/// The cells are labeled with their values if [`Config::cell_labels`] is set
+/// ```
+/// let data = [0.8, 0.1, 0.1, 0.2, 0.7, 0.1, 0.05, 0.15, 0.8];
+/// let _: complot::Heatmap = (
+///     (data.as_slice(), (3, 3)),
+///     Some(
+///         complot::Config::new()
+///             .filename("cell-labels-heatmap.png")
+///             .over_sampling_factor(100)
+///             .cell_labels(true)
+///             .cell_label_format(|v| format!("{:.0}%", v * 100.)),
+///     ),
+/// )
+///     .into();
+/// ```
+///
+/// Constant data is drawn with the color at the middle of the colormap
+/// ```
+/// let _: complot::Heatmap = (
+///     (vec![1f64; 100].as_slice(), (10, 10)),
+///     Some(complot::Config::new().filename("constant-heatmap.png")),
+/// )
+///     .into();
+/// ```
+pub struct Heatmap {
+    path: String,
+}
+impl_path!(Heatmap);
 
-            let width = size as u32 + 50;
-            let height = size as u32 + 90;
-            let root = BitMapBackend::new(&filename, (width, height)).into_drawing_area();
-            let (plot, colorbar) = root.split_vertically(size as u32 + 30);
-            // HEATMAP
-            plot.fill(&BLACK)?;
-            let mut chart = ChartBuilder::on(&plot);
-            chart
-                .margin_left(20)
-                .margin_right(20)
-                .margin_top(0)
-                .margin_bottom(0);
-            if let Some(value) = config.title {
-                chart.caption(value, ("sans-serif", 16, &WHITE));
+impl Draw for Heatmap {
+    type Data = (Vec<f64>, (usize, usize));
+    fn draw<DB>(
+        (map, (rows, cols)): &Self::Data,
+        root: &DrawingArea<DB, Shift>,
+        config: &Config,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let (rows, cols) = (*rows, *cols);
+        if rows == 0 || cols == 0 {
+            return Err(crate::Error::EmptyData);
+        }
+        let osf = config.osf;
+        let (width, height) = (cols * osf, rows * osf);
+        let lut = config.cmap_lut();
+        let size = root.dim_in_pixel();
+        let (plot, colorbar) = match config.colorbar_position {
+            ColorbarPosition::Bottom => {
+                let (plot, colorbar) = root.split_vertically(size.1.saturating_sub(60));
+                (plot, Some(colorbar))
             }
-            let mut chart_ctx = chart
-                .build_cartesian_2d(0i32..(size - 1) as i32, 0i32..(size - 1) as i32)
-                .expect("Failed building chart");
-            let (cells_min, cells_max) = match config.cmap_minmax {
-                Some(value) => value,
-                None => (
-                    map.iter()
-                        .cloned()
-                        .fold(Float::infinity(), Float::min)
-                        .as_(),
-                    map.iter()
-                        .cloned()
-                        .fold(Float::neg_infinity(), Float::max)
-                        .as_(),
+            ColorbarPosition::Right => {
+                let (plot, colorbar) = root.split_horizontally(size.0.saturating_sub(120));
+                (plot, Some(colorbar))
+            }
+            ColorbarPosition::Hidden => (root.clone(), None),
+        };
+        // the cells span the axis ranges if any is set, or else the axes are the cell indices and are hidden
+        let axes = config.xaxis.range.is_some() || config.yaxis.range.is_some();
+        // HEATMAP
+        plot.fill(&BLACK)?;
+        let mut chart = ChartBuilder::on(&plot);
+        chart
+            .margin_left(20)
+            .margin_right(20)
+            .margin_top(if axes { 10 } else { 0 })
+            .margin_bottom(0);
+        if let Some(value) = &config.title {
+            chart.caption(
+                value,
+                (
+                    config.font_family(),
+                    config.title_font_size.unwrap_or(16),
+                    &WHITE,
                 ),
-            };
-            chart_ctx
-                .configure_mesh()
-                .disable_x_mesh()
-                .disable_y_mesh()
-                .draw()?;
-            chart_ctx.draw_series(map.iter().enumerate().map(|(k, &v)| {
-                let j = (k / res) as i32;
-                let i = (k % res) as i32;
-                let u = (v.as_() - cells_min) / (cells_max - cells_min);
-                let c = cmap.eval_continuous(u as f64).as_tuple();
-                Rectangle::new(
-                    [
-                        (osf as i32 * i, osf as i32 * j),
-                        (osf as i32 * (i + 1), osf as i32 * (j + 1)),
-                    ],
-                    RGBColor(c.0, c.1, c.2).filled(),
-                )
-            }))?;
-            // COLORBAR
-            colorbar.fill(&BLACK)?;
-            let mut colorbar_chart = ChartBuilder::on(&colorbar)
-                .margin_left(20)
-                .margin_right(20)
-                .set_label_area_size(LabelAreaPosition::Bottom, 40)
-                .build_cartesian_2d(cells_min..cells_max, 0f64..1f64)?;
-            let mut mesh = colorbar_chart.configure_mesh();
-            mesh.axis_style(WHITE)
-                .set_tick_mark_size(LabelAreaPosition::Bottom, 5)
-                .x_label_style(("sans-serif", 14, &WHITE));
-            if let Some(value) = config.xaxis.label {
+            );
+        }
+        if axes {
+            chart
+                .set_label_area_size(LabelAreaPosition::Left, 50)
+                .set_label_area_size(LabelAreaPosition::Bottom, 40);
+        }
+        let xrange = config.xaxis.range.clone().unwrap_or(0f64..cols as f64);
+        let yrange = config.yaxis.range.clone().unwrap_or(0f64..rows as f64);
+        let mut chart_ctx = chart.build_cartesian_2d(xrange.clone(), yrange.clone())?;
+        let cells_range = config.cmap_range(map.iter().cloned())?;
+        let log = config.colorbar_log();
+        // the categories are written at the integer positions, the centers of the cells
+        let category = |index: Option<usize>| {
+            index
+                .and_then(|index| config.categories.as_ref()?.get(index).cloned())
+                .unwrap_or_default()
+        };
+        let position = |z: &f64| {
+            if (z - z.round()).abs() < 1e-6 && *z > -0.5 {
+                Some(z.round() as usize)
+            } else {
+                None
+            }
+        };
+        let x_category = |x: &f64| category(position(x));
+        let y_category = |y: &f64| {
+            category(position(y).and_then(|j| match config.origin {
+                Origin::TopLeft => (rows - 1).checked_sub(j),
+                Origin::BottomLeft => Some(j),
+            }))
+        };
+        let mut mesh = chart_ctx.configure_mesh();
+        if axes {
+            config.mesh_style(&mut mesh);
+            let font = (
+                config.font_family(),
+                config.tick_font_size.unwrap_or(12),
+                &WHITE,
+            );
+            mesh.label_style(font)
+                .axis_desc_style((
+                    config.font_family(),
+                    config.label_font_size.unwrap_or(12),
+                    &WHITE,
+                ))
+                .axis_style(WHITE);
+            if config.categories.is_some() {
+                mesh.x_labels(cols)
+                    .x_label_formatter(&x_category)
+                    .y_labels(rows)
+                    .y_label_formatter(&y_category);
+            }
+            if let Some(value) = config.xaxis.scaled_label() {
                 mesh.x_desc(value);
             }
-            mesh.draw()?;
-            let dx = (cells_max - cells_min) / (size - 1) as f64;
-            colorbar_chart.draw_series((0..size).map(|k| {
-                let x = cells_min + k as f64 * dx;
-                let c = cmap.eval_rational(k, size).as_tuple();
-                Rectangle::new([(x, 0.), (x + dx, 1.)], RGBColor(c.0, c.1, c.2).filled())
+            if let Some(value) = config.yaxis.scaled_label() {
+                mesh.y_desc(value);
+            }
+        }
+        mesh.disable_x_mesh().disable_y_mesh().draw()?;
+        // the colors are computed first, in parallel with the rayon feature, and then drawn
+        let nan_color = RGBColor(config.nan_color.0, config.nan_color.1, config.nan_color.2);
+        let color = |v: &f64| {
+            if v.is_nan() {
+                return nan_color;
+            }
+            let u = normalize(*v, &cells_range, log).clamp(0., 1.);
+            lut[(u * (lut.len() - 1) as f64).round() as usize]
+        };
+        #[cfg(feature = "rayon")]
+        let colors: Vec<RGBColor> = map.par_iter().map(color).collect();
+        #[cfg(not(feature = "rayon"))]
+        let colors: Vec<RGBColor> = map.iter().map(color).collect();
+        let (dx, dy) = (
+            (xrange.end - xrange.start) / cols as f64,
+            (yrange.end - yrange.start) / rows as f64,
+        );
+        // the lower left corner of the k-th cell
+        let corner = |k: usize| {
+            let j = match config.origin {
+                Origin::TopLeft => rows - 1 - k / cols,
+                Origin::BottomLeft => k / cols,
+            } as f64;
+            let i = (k % cols) as f64;
+            (xrange.start + i * dx, yrange.start + j * dy)
+        };
+        chart_ctx.draw_series(colors.iter().enumerate().map(|(k, color)| {
+            let (x, y) = corner(k);
+            Rectangle::new([(x, y), (x + dx, y + dy)], color.filled())
+        }))?;
+        if config.cell_labels {
+            let font = (config.font_family(), config.tick_font_size.unwrap_or(12)).into_font();
+            chart_ctx.draw_series(map.iter().zip(&colors).enumerate().map(|(k, (v, color))| {
+                let (x, y) = corner(k);
+                // dark text on light cells and light text on dark cells
+                let RGBColor(r, g, b) = *color;
+                let luminance = 0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64;
+                let text_color = if luminance > 128. { BLACK } else { WHITE };
+                let label = match config.cell_label_format {
+                    Some(format) => format(v),
+                    None if v.fract() == 0. => format!("{}", v),
+                    None => format!("{:.2}", v),
+                };
+                Text::new(
+                    label,
+                    (x + 0.5 * dx, y + 0.5 * dy),
+                    font.color(&text_color)
+                        .pos(Pos::new(HPos::Center, VPos::Center)),
+                )
             }))?;
-            Ok(())
         }
-        if let Err(e) = inner((data, config)) {
-            eprintln!("Complot failed in Heatmap: {}", e);
+        // COLORBAR
+        let colorbar = match colorbar {
+            Some(colorbar) => colorbar,
+            None => return Ok(()),
+        };
+        colorbar.fill(&BLACK)?;
+        let range = config
+            .colorbar
+            .as_ref()
+            .and_then(|c| c.range.clone())
+            .unwrap_or_else(|| cells_range.clone());
+        let label = match &config.colorbar {
+            Some(Colorbar { label, .. }) => label.as_ref(),
+            None if !axes => config.xaxis.label.as_ref(),
+            None => None,
+        };
+        if config.colorbar_position == ColorbarPosition::Right {
+            // the colorbar is aligned with the heatmap, leaving room for the end tick labels
+            let (_, y_pixels) = chart_ctx.plotting_area().get_pixel_range();
+            config.draw_colorbar(
+                ChartBuilder::on(&colorbar)
+                    .margin_left(10)
+                    .margin_right(10)
+                    .margin_top(y_pixels.start.max(8))
+                    .margin_bottom((size.1 as i32 - y_pixels.end).max(8))
+                    .set_label_area_size(LabelAreaPosition::Right, 70),
+                range,
+                &cells_range,
+                height,
+                label,
+                true,
+            )?;
+        } else {
+            // the colorbar is aligned with the heatmap
+            let (x_pixels, _) = chart_ctx.plotting_area().get_pixel_range();
+            let x0 = root.get_base_pixel().0;
+            config.draw_colorbar(
+                ChartBuilder::on(&colorbar)
+                    .margin_left(x_pixels.start - x0)
+                    .margin_right(size.0 as i32 - x_pixels.end + x0)
+                    .set_label_area_size(LabelAreaPosition::Bottom, 40),
+                range,
+                &cells_range,
+                width,
+                label,
+                false,
+            )?;
+        }
+        Ok(())
+    }
+}
+impl Heatmap {
+    /// Draws the heatmap like the [`From`] conversion but writes the graph into `writer`
+    ///
+    /// The graph is written as SVG unless a bitmap format is set with [`Config::format`]
+    /// ```
+    /// fn main() -> complot::Result<()> {
+    ///     let data: Vec<f64> = (0..100).map(|k| (k as f64 / 10.).sin()).collect();
+    ///     let mut png = Vec::new();
+    ///     complot::Heatmap::write_to(
+    ///         (data.as_slice(), (10, 10)),
+    ///         Some(complot::Config::new().format(complot::ImageFormat::Png)),
+    ///         &mut png,
+    ///     )?;
+    ///     assert!(png.starts_with(b"\x89PNG"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn write_to<T, W>(data: Data<T>, config: Option<Config>, writer: W) -> Result<()>
+    where
+        T: Float + AsPrimitive<f64>,
+        W: Write,
+    {
+        let config = config.unwrap_or_default();
+        let (data, size) = Heatmap::collect(data, &config)?;
+        config.render_to_writer::<Heatmap, W>(&data, writer, size)
+    }
+    /// Draws the heatmap of the data `(data, (rows, columns))` on the drawing area `root`
+    /// supplied by the caller, see [`Plot::draw_on`](crate::Plot::draw_on)
+    pub fn draw_on<DB, T>(
+        root: &DrawingArea<DB, Shift>,
+        data: Data<T>,
+        config: Option<Config>,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+        T: Float + AsPrimitive<f64>,
+    {
+        let config = config.unwrap_or_default();
+        let (data, _) = Heatmap::collect(data, &config)?;
+        config.draw_on::<Heatmap, DB>(&data, root)
+    }
+    /// Converts the data to `f64` and returns it with the default size of the graph
+    pub(crate) fn collect<T>(
+        (map, (rows, cols)): Data<T>,
+        config: &Config,
+    ) -> Result<(<Heatmap as Draw>::Data, (u32, u32))>
+    where
+        T: Float + AsPrimitive<f64>,
+    {
+        if map.len() != rows * cols {
+            return Err(crate::Error::ShapeMismatch {
+                len: map.len(),
+                rows,
+                cols,
+            });
         }
-        Heatmap {}
+        let osf = config.osf;
+        let (mut width, mut height) = ((cols * osf) as u32 + 50, (rows * osf) as u32 + 30);
+        if config.xaxis.range.is_some() || config.yaxis.range.is_some() {
+            // the label areas of the axes and the room for the top tick label
+            width += 50;
+            height += 50;
+        }
+        Ok((
+            (map.iter().map(|x| x.as_()).collect(), (rows, cols)),
+            match config.colorbar_position {
+                ColorbarPosition::Bottom => (width, height + 60),
+                ColorbarPosition::Right => (width + 120, height),
+                ColorbarPosition::Hidden => (width, height),
+            },
+        ))
+    }
+}
+impl<'a, T: Float + AsPrimitive<f64>> From<(Data<'a, T>, Option<Config>)> for Heatmap {
+    fn from((data, config): (Data<T>, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let path = Heatmap::collect(data, &config)
+            .and_then(|(data, size)| config.render::<Heatmap>(&data, "complot-heatmap.png", size))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Heatmap: {}", e);
+                String::new()
+            });
+        Heatmap { path }
     }
 }
@@ -0,0 +1,194 @@
+use super::{Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Largest number of bins of a histogram
+pub(crate) const MAX_BINS: usize = 10_000;
+
+/// Histogram stacking mode
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum HistMode {
+    /// The histograms are drawn over each other with transparency
+    #[default]
+    Overlay,
+    /// The histograms are stacked on top of each other
+    Stack,
+}
+
+/// Histograms
+///
+/// Bins each group of samples on a common set of bin edges, the NaN and infinite samples are skipped
+/// ```
+/// let samples = |shift: f64| {
+///     (0..1000)
+///         .map(|k| shift + (k as f64 * 0.37).sin() + (k as f64 * 0.11).cos())
+///         .collect::<Vec<f64>>()
+/// };
+/// let _: complot::Histogram = (
+///     vec![samples(0.), samples(1.)].into_iter(),
+///     Some(
+///         complot::Config::new()
+///             .filename("histograms.svg")
+///             .hist_mode(complot::HistMode::Overlay),
+///     ),
+/// )
+///     .into();
+/// ```
+pub struct Histogram {
+    path: String,
+}
+impl_path!(Histogram);
+
+impl Histogram {
+    /// Returns the bin edges common to all the groups of samples
+    fn edges(data: &[Vec<f64>], config: &Config) -> Vec<f64> {
+        let (mut min, mut max) = data
+            .iter()
+            .flatten()
+            .filter(|x| x.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), &x| {
+                (a.min(x), b.max(x))
+            });
+        if min > max {
+            // no samples
+            (min, max) = (0., 1.);
+        }
+        if min == max {
+            min -= 0.5;
+            max += 0.5;
+        }
+        let (n_bin, width) = match config.bin_width {
+            Some(width) if width > 0. && (max - min) / width <= MAX_BINS as f64 => {
+                (((max - min) / width).ceil().max(1.) as usize, width)
+            }
+            Some(width) if width > 0. => (MAX_BINS, (max - min) / MAX_BINS as f64),
+            _ => {
+                let n_sample = data.iter().flatten().filter(|x| x.is_finite()).count();
+                // Sturges' rule
+                let n_bin = config
+                    .bins
+                    .unwrap_or_else(|| 1 + (n_sample.max(1) as f64).log2().ceil() as usize)
+                    .min(MAX_BINS);
+                (n_bin, (max - min) / n_bin as f64)
+            }
+        };
+        (0..=n_bin).map(|k| min + k as f64 * width).collect()
+    }
+    /// Returns the number of samples of `data` in each bin
+    fn counts(data: &[f64], edges: &[f64]) -> Vec<f64> {
+        let n_bin = edges.len() - 1;
+        let (min, max) = (edges[0], edges[n_bin]);
+        let mut counts = vec![0f64; n_bin];
+        data.iter().filter(|x| x.is_finite()).for_each(|&x| {
+            let k = ((x - min) / (max - min) * n_bin as f64) as usize;
+            counts[k.min(n_bin - 1)] += 1.;
+        });
+        counts
+    }
+}
+
+impl Draw for Histogram {
+    type Data = [Vec<f64>];
+    fn draw<DB>(data: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let edges = Histogram::edges(data, config);
+        let n_bin = edges.len() - 1;
+        let counts: Vec<_> = data.iter().map(|x| Histogram::counts(x, &edges)).collect();
+        let bottoms: Vec<Vec<f64>> = match config.hist_mode {
+            HistMode::Overlay => vec![vec![0f64; n_bin]; counts.len()],
+            HistMode::Stack => counts
+                .iter()
+                .scan(vec![0f64; n_bin], |bottom, count| {
+                    let this_bottom = bottom.clone();
+                    bottom.iter_mut().zip(count).for_each(|(b, c)| *b += c);
+                    Some(this_bottom)
+                })
+                .collect(),
+        };
+        let y_max = counts
+            .iter()
+            .zip(&bottoms)
+            .flat_map(|(count, bottom)| count.iter().zip(bottom).map(|(c, b)| c + b))
+            .fold(0f64, f64::max);
+        let y_max = if y_max > 0. { y_max } else { 1. };
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(edges[0]..edges[n_bin], 0f64..y_max)?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = &config.xaxis.label {
+            mesh.x_desc(value);
+        }
+        mesh.y_desc(config.yaxis.label.as_deref().unwrap_or("Count"));
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+
+        let alpha = match config.hist_mode {
+            HistMode::Overlay => 0.5,
+            HistMode::Stack => 1.,
+        };
+        let mut legend = config.legend.iter().flatten();
+        for (k, (count, bottom)) in counts.iter().zip(&bottoms).enumerate() {
+            let rgb = config.series_color(k);
+            let series = chart.draw_series(count.iter().zip(bottom).zip(edges.windows(2)).map(
+                |((c, b), e)| Rectangle::new([(e[0], *b), (e[1], b + c)], rgb.mix(alpha).filled()),
+            ))?;
+            if let Some(key) = legend.next() {
+                series.label(key).legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 20, y + 5)], rgb.mix(alpha).filled())
+                });
+            }
+        }
+        if config.legend.is_some() {
+            config.draw_legend(fig, &mut chart, SeriesLabelPosition::UpperRight)?;
+        }
+        Ok(())
+    }
+}
+
+/// Histogram of the samples given by the iterator, the graph is written in the file `complot-histogram.png`
+/// ```
+/// (0..1000)
+///     .map(|k| (k as f64 * 0.37).sin() + (k as f64 * 0.11).cos())
+///     .collect::<complot::Histogram>();
+/// let _: complot::Histogram = (
+///     std::iter::once(vec![1f64; 10]),
+///     Some(complot::Config::new().filename("histogram.svg").bins(5)),
+/// )
+///     .into();
+/// let _: complot::Histogram = (
+///     std::iter::once(Vec::<f64>::new()),
+///     Some(complot::Config::new().filename("empty-histogram.svg")),
+/// )
+///     .into();
+/// ```
+impl FromIterator<f64> for Histogram {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        (
+            std::iter::once(iter.into_iter().collect::<Vec<f64>>()),
+            None,
+        )
+            .into()
+    }
+}
+
+/// Overlaid or stacked histograms of the groups of samples given by the iterator
+impl<I: Iterator<Item = Vec<f64>>> From<(I, Option<Config>)> for Histogram {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<Histogram>(&data, "complot-histogram", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Histogram: {}", e);
+                String::new()
+            });
+        Histogram { path }
+    }
+}
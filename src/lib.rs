@@ -26,7 +26,7 @@
 
 /// Macro to set some graph properties
 ///
-/// Returns [`Some`] [`Config`]
+/// Returns [`Some`] [`Config`], see [`config!`] for the keywords
 /// ```
 /// # #[macro_use] extern crate complot;
 /// # fn main() {
@@ -35,43 +35,70 @@
 /// complot!("filename", ylabel="xlabel");
 /// complot!("filename", xlabel="xlabel", ylabel="ylabel");
 /// complot!("filename", xlabel="xlabel", ylabel="ylabel", title="title");
+/// complot!("filename", title="title");
+/// complot!("filename", size=(400, 300), yrange=-1.0..1.0, xlabel="xlabel");
 /// # }
 ///```
 #[macro_export]
 macro_rules! complot {
-    ($filename:expr) => {
-        Some(complot::Config::new().filename($filename))
+    ($($args:tt)*) => {
+        Some($crate::config!($($args)*))
     };
-    ($filename:expr, xlabel=$xlabel:expr) => {
-        Some(
-            complot::Config::new()
-                .filename($filename)
-                .xaxis(complot::Axis::new().label($xlabel)),
-        )
+}
+
+/// Macro to set some graph properties
+///
+/// Returns a [`Config`], with the graph filename followed by any of the keywords, in any order:
+///  - `xlabel`, `ylabel`: axis labels, see [`Config::xlabel`] and [`Config::ylabel`],
+///  - `xrange`, `yrange`: axis ranges, see [`Config::xlim`] and [`Config::ylim`],
+///  - `size`: `(width, height)` in pixels, see [`Config::size`],
+///  - any other [`Config`] method with a single argument, e.g. `title`
+///
+/// The [`Config`] methods can be chained to the macro
+/// ```
+/// let _: complot::Plot = (
+///     (0..100).map(|k| {
+///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+///         (o, vec![o.sin()])
+///     }),
+///     Some(complot::config!("config.svg", xlabel = "x", title = "Sine").markers(true)),
+/// )
+///     .into();
+/// ```
+#[macro_export]
+macro_rules! config {
+    (@config $config:expr) => {
+        $config
     };
-    ($filename:expr, ylabel=$ylabel:expr) => {
-        Some(
-            complot::Config::new()
-                .filename($filename)
-                .yaxis(complot::Axis::new().label($ylabel)),
-        )
+    (@config $config:expr, xrange = $value:expr $(, $key:ident = $values:expr)*) => {
+        $crate::config!(@config $config.xlim($value) $(, $key = $values)*)
     };
-    ($filename:expr, xlabel=$xlabel:expr, ylabel=$ylabel:expr) => {
-        Some(
-            complot::Config::new()
-                .filename($filename)
-                .xaxis(complot::Axis::new().label($xlabel))
-                .yaxis(complot::Axis::new().label($ylabel)),
-        )
+    (@config $config:expr, yrange = $value:expr $(, $key:ident = $values:expr)*) => {
+        $crate::config!(@config $config.ylim($value) $(, $key = $values)*)
     };
-    ($filename:expr, xlabel=$xlabel:expr, ylabel=$ylabel:expr, title=$title:expr) => {
-        Some(
-            complot::Config::new()
-                .filename($filename)
-                .xaxis(complot::Axis::new().label($xlabel))
-                .yaxis(complot::Axis::new().label($ylabel))
-                .title($title),
-        )
+    (@config $config:expr, size = $value:expr $(, $key:ident = $values:expr)*) => {
+        $crate::config!(@config {
+            let (width, height) = $value;
+            $config.size(width, height)
+        } $(, $key = $values)*)
+    };
+    (@config $config:expr, $key:ident = $value:expr $(, $keys:ident = $values:expr)*) => {
+        $crate::config!(@config $config.$key($value) $(, $keys = $values)*)
+    };
+    ($filename:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        $crate::config!(@config $crate::Config::new().filename($filename) $(, $key = $value)*)
+    };
+}
+
+/// Implements `path()` for the graphs that keep the path of the file they are written to
+macro_rules! impl_path {
+    ($graph:ty) => {
+        impl $graph {
+            /// Returns the path of the file the graph is written to, empty if the graph could not be drawn
+            pub fn path(&self) -> &str {
+                &self.path
+            }
+        }
     };
 }
 
@@ -79,32 +106,482 @@ mod line;
 pub use line::{LinLog, LogLin, LogLog, Plot};
 mod scatter;
 pub use scatter::Scatter;
-use std::ops::Range;
+use std::{collections::HashMap, fmt, ops::Range, path::Path, sync::Arc};
 mod combo;
 pub mod tri;
-pub use combo::{Combo, Complot, Kind};
-use plotters::{coord::Shift, prelude::*};
+pub use combo::{Combo, ComboBuilder, Complot, Kind};
+#[cfg(feature = "png")]
+use image::ImageEncoder;
+use plotters::{
+    chart::{MeshStyle, SeriesAnno},
+    coord::{ranged1d::ValueFormatter, Shift},
+    prelude::*,
+    style::text_anchor::{HPos, Pos, VPos},
+};
+
+/// Width in pixels of the margin reserved for the legend outside of the plotting area
+const LEGEND_WIDTH: u32 = 150;
+
+mod scaled;
+use scaled::ScaledBackend;
 mod heatmap;
-pub use heatmap::Heatmap;
+pub use heatmap::{ColorbarPosition, Heatmap, Origin};
+mod histogram;
+pub use histogram::{HistMode, Histogram};
+mod sparkline;
+pub use sparkline::{sparkline, Sparkline};
+mod residuals;
+pub use residuals::with_residuals;
+mod errorbar;
+pub use errorbar::ErrorBar;
+mod bar;
+pub use bar::{Bar, CategoryBar, Orientation};
+mod area;
+pub use area::StackedArea;
+mod pie;
+pub use pie::Pie;
+mod boxplot;
+pub use boxplot::BoxPlot;
+mod twin;
+pub use twin::TwinPlot;
+mod band;
+pub use band::Band;
+mod color_scatter;
+pub use color_scatter::ColorScatter;
+mod grid;
+pub use grid::Grid;
+mod bubble;
+pub use bubble::Bubble;
+mod scatter_heatmap;
+pub use scatter_heatmap::{Aggregation, ScatterHeatmap};
+mod animation;
+pub use animation::Animation;
+mod confusion;
+pub use confusion::ConfusionMatrix;
+
+/// Complot error
+///
+/// ```
+/// let rows = vec![(0., vec![1., 2.]), (1., vec![3.])];
+/// match complot::Plot::write_to(rows.into_iter(), None, std::io::sink()) {
+///     Err(complot::Error::InconsistentSeriesLength { row, expected, got }) => {
+///         assert_eq!((row, expected, got), (1, 2, 1))
+///     }
+///     _ => panic!("the ragged rows should be rejected"),
+/// }
+/// ```
+///
+/// The errors of the drawing backend are kept as the error source
+/// ```
+/// use std::error::Error;
+/// match complot::Plot::build(
+///     (0..10).map(|k| (k as f64, vec![k as f64])),
+///     Some(complot::Config::new().filename("no/such/directory/error.svg")),
+/// ) {
+///     Err(e @ complot::Error::Backend(_)) => assert!(e.source().is_some()),
+///     _ => panic!("the graph should not be written"),
+/// }
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// There is no data to plot
+    EmptyData,
+    /// The number of values in a row differs from the number of values in the first row
+    InconsistentSeriesLength {
+        row: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// The number of values of a series differs from the number of x values
+    LengthMismatch {
+        series: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// The length of the data of a grid differs from the number of rows times the number of columns
+    ShapeMismatch {
+        len: usize,
+        rows: usize,
+        cols: usize,
+    },
+    /// The x value at `index` is smaller than the previous one
+    UnsortedX { index: usize },
+    /// The colormap name is not known
+    UnknownColormap(String),
+    /// A value is zero or negative on a log10 axis
+    NonPositiveOnLogAxis { axis: char, value: f64 },
+    /// The drawing backend or the image encoder failed
+    Backend(Box<dyn std::error::Error + Send + Sync>),
+    /// Reading or writing a file failed
+    Io(std::io::Error),
+    /// Any other failure, e.g. returned by a [`Config::draw_callback`] or a [`Config::chart_callback`]
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyData => write!(f, "No data to plot"),
+            Error::InconsistentSeriesLength { row, expected, got } => write!(
+                f,
+                "Row {} has {} values instead of {} as the first row",
+                row, got, expected
+            ),
+            Error::LengthMismatch {
+                series,
+                expected,
+                got,
+            } => write!(
+                f,
+                "Series {} has {} values instead of {} as the x values",
+                series, got, expected
+            ),
+            Error::ShapeMismatch { len, rows, cols } => write!(
+                f,
+                "Data length {} does not match {} rows x {} columns",
+                len, rows, cols
+            ),
+            Error::UnsortedX { index } => write!(
+                f,
+                "x values are not increasing at x[{}], sort the data by x or set `Config::sort_x`",
+                index
+            ),
+            Error::UnknownColormap(name) => write!(f, "Unknown colormap: {}", name),
+            Error::NonPositiveOnLogAxis { axis, value } => {
+                write!(f, "Non-positive {} value on a log10 axis: {}", axis, value)
+            }
+            Error::Backend(e) => write!(f, "Drawing failed: {}", e),
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Other(e) => write!(f, "{}", e),
+        }
+    }
+}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Backend(e) | Error::Other(e) => Some(e.as_ref()),
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+impl<E: std::error::Error + Send + Sync + 'static> From<DrawingAreaErrorKind<E>> for Error {
+    fn from(e: DrawingAreaErrorKind<E>) -> Self {
+        Error::Backend(Box::new(e))
+    }
+}
+#[cfg(feature = "png")]
+impl From<image::ImageError> for Error {
+    fn from(e: image::ImageError) -> Self {
+        match e {
+            image::ImageError::IoError(e) => Error::Io(e),
+            e => Error::Backend(Box::new(e)),
+        }
+    }
+}
+/// Converts the error returned by a callback, a complot error is passed through unchanged
+impl From<Box<dyn std::error::Error + Send + Sync>> for Error {
+    fn from(e: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        match e.downcast::<Error>() {
+            Ok(e) => *e,
+            Err(e) => Error::Other(e),
+        }
+    }
+}
+/// Complot result
+pub type Result<T> = std::result::Result<T, Error>;
+/// Result of the [`Config::draw_callback`] and [`Config::chart_callback`] hooks,
+/// any error can be returned with `?`
+pub type CallbackResult = std::result::Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
+/// Creates a white `(width, height)` SVG drawing area written to `filename`
+///
+/// The SVG canvas takes precedence if both the `svg` and the `png` features are enabled
 #[cfg(feature = "svg")]
-pub fn canvas(filename: &str, size: (u32, u32)) -> DrawingArea<SVGBackend, Shift> {
-    let plot = SVGBackend::new(filename, size).into_drawing_area();
+pub fn canvas(filename: &str, size: (u32, u32)) -> DrawingArea<SVGBackend<'_>, Shift> {
+    let plot = blank_canvas(filename, size);
     plot.fill(&WHITE).unwrap();
     plot
 }
-#[cfg(feature = "png")]
-pub fn canvas(filename: &str, size: (u32, u32)) -> DrawingArea<BitMapBackend, Shift> {
-    let plot = BitMapBackend::new(filename, size).into_drawing_area();
+/// Creates a `(width, height)` SVG drawing area written to `filename` without background
+#[cfg(feature = "svg")]
+fn blank_canvas(filename: &str, size: (u32, u32)) -> DrawingArea<SVGBackend<'_>, Shift> {
+    SVGBackend::new(filename, size).into_drawing_area()
+}
+/// Creates a white `(width, height)` bitmap drawing area written to `filename`
+#[cfg(all(feature = "png", not(feature = "svg")))]
+pub fn canvas(filename: &str, size: (u32, u32)) -> DrawingArea<BitMapBackend<'_>, Shift> {
+    let plot = blank_canvas(filename, size);
     plot.fill(&WHITE).unwrap();
     plot
 }
+/// Creates a `(width, height)` bitmap drawing area written to `filename` without background
+#[cfg(all(feature = "png", not(feature = "svg")))]
+fn blank_canvas(filename: &str, size: (u32, u32)) -> DrawingArea<BitMapBackend<'_>, Shift> {
+    BitMapBackend::new(filename, size).into_drawing_area()
+}
+/// Creates a white 768x512 SVG drawing area written to `filename`
+#[cfg(feature = "svg")]
+pub fn canvas_default(filename: &str) -> DrawingArea<SVGBackend<'_>, Shift> {
+    canvas(filename, (768, 512))
+}
+/// Creates a white 768x512 bitmap drawing area written to `filename`
+#[cfg(all(feature = "png", not(feature = "svg")))]
+pub fn canvas_default(filename: &str) -> DrawingArea<BitMapBackend<'_>, Shift> {
+    canvas(filename, (768, 512))
+}
+
+/// Backend agnostic drawing of a graph
+trait Draw {
+    /// The data the graph is drawn from
+    type Data: ?Sized;
+    /// Draws the graph onto the drawing area `root`
+    fn draw<DB>(data: &Self::Data, root: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static;
+}
+
+/// Backend agnostic drawing surface handed to the [`Config::draw_callback`] hook
+///
+/// Coordinates are in pixels from the upper left corner of the figure
+pub trait Overlay {
+    /// Returns the figure size in pixels
+    fn size(&self) -> (u32, u32);
+    /// Writes `text` at `position` with the given font size and color
+    fn text(
+        &self,
+        text: &str,
+        position: (i32, i32),
+        font_size: f64,
+        color: (u8, u8, u8),
+    ) -> Result<()>;
+    /// Draws a line from `start` to `end`
+    fn line(&self, start: (i32, i32), end: (i32, i32), color: (u8, u8, u8)) -> Result<()>;
+    /// Draws a filled rectangle between the upper left and lower right corners with the given opacity
+    fn rectangle(
+        &self,
+        upper_left: (i32, i32),
+        lower_right: (i32, i32),
+        color: (u8, u8, u8),
+        alpha: f64,
+    ) -> Result<()>;
+}
+impl<DB> Overlay for DrawingArea<DB, Shift>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    fn size(&self) -> (u32, u32) {
+        self.dim_in_pixel()
+    }
+    fn text(
+        &self,
+        text: &str,
+        position: (i32, i32),
+        font_size: f64,
+        color: (u8, u8, u8),
+    ) -> Result<()> {
+        let style = ("sans-serif", font_size)
+            .into_font()
+            .color(&RGBColor(color.0, color.1, color.2));
+        self.draw(&Text::new(text, position, style))?;
+        Ok(())
+    }
+    fn line(&self, start: (i32, i32), end: (i32, i32), color: (u8, u8, u8)) -> Result<()> {
+        self.draw(&PathElement::new(
+            vec![start, end],
+            RGBColor(color.0, color.1, color.2),
+        ))?;
+        Ok(())
+    }
+    fn rectangle(
+        &self,
+        upper_left: (i32, i32),
+        lower_right: (i32, i32),
+        color: (u8, u8, u8),
+        alpha: f64,
+    ) -> Result<()> {
+        self.draw(&Rectangle::new(
+            [upper_left, lower_right],
+            RGBColor(color.0, color.1, color.2).mix(alpha).filled(),
+        ))?;
+        Ok(())
+    }
+}
+/// Backend agnostic chart handed to the [`Config::chart_callback`] hook
+///
+/// Coordinates are in the units of the axes, i.e. after the [axis scale factor](Axis::scale_factor) is applied
+pub trait ChartOverlay {
+    /// Returns the x-axis range
+    fn x_range(&self) -> Range<f64>;
+    /// Returns the y-axis range
+    fn y_range(&self) -> Range<f64>;
+    /// Draws a polyline through `points`
+    fn line(&mut self, points: &[(f64, f64)], color: (u8, u8, u8)) -> Result<()>;
+    /// Writes `text` at `position` with the given font size and color
+    fn text(
+        &mut self,
+        text: &str,
+        position: (f64, f64),
+        font_size: f64,
+        color: (u8, u8, u8),
+    ) -> Result<()>;
+}
+impl<DB, X, Y> ChartOverlay for ChartContext<'_, DB, Cartesian2d<X, Y>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    fn x_range(&self) -> Range<f64> {
+        ChartContext::x_range(self)
+    }
+    fn y_range(&self) -> Range<f64> {
+        ChartContext::y_range(self)
+    }
+    fn line(&mut self, points: &[(f64, f64)], color: (u8, u8, u8)) -> Result<()> {
+        self.draw_series(LineSeries::new(
+            points.iter().cloned(),
+            RGBColor(color.0, color.1, color.2),
+        ))?;
+        Ok(())
+    }
+    fn text(
+        &mut self,
+        text: &str,
+        position: (f64, f64),
+        font_size: f64,
+        color: (u8, u8, u8),
+    ) -> Result<()> {
+        let style = ("sans-serif", font_size)
+            .into_font()
+            .color(&RGBColor(color.0, color.1, color.2));
+        self.draw_series(std::iter::once(Text::new(
+            text.to_string(),
+            position,
+            style,
+        )))?;
+        Ok(())
+    }
+}
+type Callback = dyn Fn(&dyn Overlay) -> CallbackResult + Send + Sync;
+type ChartCallbackFn = dyn Fn(&mut dyn ChartOverlay) -> CallbackResult + Send + Sync;
+/// Chart callback
+#[derive(Clone)]
+struct ChartCallback(Arc<ChartCallbackFn>);
+impl fmt::Debug for ChartCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ChartCallback")
+    }
+}
+/// Post-draw callback
+#[derive(Clone)]
+struct DrawCallback(Arc<Callback>);
+impl fmt::Debug for DrawCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DrawCallback")
+    }
+}
+
+/// Line stroke style
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum LineStyle {
+    /// Continuous line
+    #[default]
+    Solid,
+    /// Dashed line
+    Dashed,
+    /// Dotted line
+    Dotted,
+}
+
+/// Line drawing mode between consecutive points
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum LineMode {
+    /// Straight segments
+    #[default]
+    Straight,
+    /// Stairs where the value changes at the start of each interval: vertical then horizontal segments
+    PreStep,
+    /// Stairs where the value changes at the end of each interval: horizontal then vertical segments
+    PostStep,
+}
+
+/// Scatter plot marker shape
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum MarkerShape {
+    /// Circle
+    #[default]
+    Circle,
+    /// Square
+    Square,
+    /// Diagonal cross
+    Cross,
+    /// Upward pointing triangle
+    TriangleUp,
+}
+
+/// Position of the legend
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LegendPosition {
+    /// Upper left corner of the plotting area
+    UpperLeft,
+    /// Upper right corner of the plotting area
+    UpperRight,
+    /// Lower left corner of the plotting area
+    LowerLeft,
+    /// Lower right corner of the plotting area
+    LowerRight,
+    /// Right of the plotting area, in a margin reserved for the legend
+    Outside,
+}
+
+/// Horizontal alignment of the graph title
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum TitleAlign {
+    /// Aligned with the left margin of the graph
+    Left,
+    /// Centered on the graph
+    #[default]
+    Center,
+}
+
+/// Image format of the graph files
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFormat {
+    /// Scalable vector graphics
+    Svg,
+    /// Portable network graphics
+    Png,
+    /// JPEG with the given quality, from 1 to 100
+    Jpeg { quality: u8 },
+}
+
+/// Axis scale
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Scale {
+    /// Linear axis
+    #[default]
+    Linear,
+    /// Logarithmic axis with decade ticks
+    Log10,
+}
 
 /// Axis properties
 #[derive(Default, Clone, Debug)]
 pub struct Axis {
     label: Option<String>,
     range: Option<Range<f64>>,
+    scale_factor: Option<f64>,
+    ticks: Option<usize>,
+    tick_values: Option<Vec<f64>>,
+    format: Option<fn(&f64) -> String>,
 }
 impl Axis {
     /// Creates a new axis
@@ -128,22 +605,295 @@ impl Axis {
             ..self
         }
     }
+    /// Sets the axis scale factor
+    ///
+    /// The data is divided by the scale factor and the multiplier is appended to the axis label
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![1e3 * o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("scale_factor.svg")
+    ///             .yaxis(complot::Axis::new().label("y").scale_factor(1e3)),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("scale_factor.svg").unwrap().contains("y (×10³)"));
+    /// ```
+    /// Only finite and positive scale factors are accepted, the others are ignored
+    /// ```
+    /// for scale_factor in [0., -1e3, f64::NAN, f64::INFINITY] {
+    ///     let svg = complot::Plot::to_svg_string(
+    ///         (0..10).map(|k| (k as f64, vec![k as f64])),
+    ///         Some(complot::Config::new().yaxis(
+    ///             complot::Axis::new().label("y").scale_factor(scale_factor),
+    ///         )),
+    ///     )
+    ///     .unwrap();
+    ///     assert!(!svg.contains("×"));
+    /// }
+    /// ```
+    pub fn scale_factor(self, scale_factor: f64) -> Self {
+        if !(scale_factor.is_finite() && scale_factor > 0.) {
+            return self;
+        }
+        Self {
+            scale_factor: Some(scale_factor),
+            ..self
+        }
+    }
+    /// Sets the maximum number of tick labels
+    pub fn ticks(self, ticks: usize) -> Self {
+        Self {
+            ticks: Some(ticks),
+            ..self
+        }
+    }
+    /// Sets the values of the ticks, overriding the automatic tick placement
+    ///
+    /// The values outside the axis range are skipped
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..=360).map(|k| (k as f64, vec![(k as f64).to_radians().sin()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("tick_values.svg")
+    ///             .xaxis(
+    ///                 complot::Axis::new()
+    ///                     .label("angle [deg]")
+    ///                     .tick_values(vec![0., 90., 180., 270., 360.]),
+    ///             ),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("tick_values.svg").unwrap().contains("270.0"));
+    /// ```
+    pub fn tick_values(self, tick_values: Vec<f64>) -> Self {
+        Self {
+            tick_values: Some(tick_values),
+            ..self
+        }
+    }
+    /// Sets the tick labels formatter
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64 / 100., vec![(k as f64 / 100.).powi(2)])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("ticks.svg")
+    ///             .yaxis(
+    ///                 complot::Axis::new()
+    ///                     .ticks(5)
+    ///                     .format(|y| format!("{:.0}%", y * 100.)),
+    ///             ),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("ticks.svg").unwrap().contains("40%"));
+    /// ```
+    pub fn format(self, format: fn(&f64) -> String) -> Self {
+        Self {
+            format: Some(format),
+            ..self
+        }
+    }
+    /// Returns the scale factor or 1 if it is not set
+    fn scale(&self) -> f64 {
+        self.scale_factor.unwrap_or(1.)
+    }
+    /// Returns the axis label with the scale factor multiplier
+    fn scaled_label(&self) -> Option<String> {
+        match (&self.label, self.scale_factor) {
+            (Some(label), Some(scale_factor)) => {
+                Some(format!("{} ({})", label, multiplier(scale_factor)))
+            }
+            (None, Some(scale_factor)) => Some(multiplier(scale_factor)),
+            (label, None) => label.clone(),
+        }
+    }
+}
+/// Expands `range` by the fraction `padding` of its width on each side,
+/// an empty range becomes `0..1` and a single value is centered in a range of width 1
+fn padded(range: Range<f64>, padding: f64) -> Range<f64> {
+    let width = range.end - range.start;
+    if width > 0. {
+        range.start - padding * width..range.end + padding * width
+    } else if width == 0. {
+        range.start - 0.5..range.end + 0.5
+    } else {
+        0f64..1f64
+    }
+}
+/// Returns the smallest gap between 2 consecutive values of `x`, 1 if there is a single value,
+/// that sets the width of the bars and of the boxes
+fn min_gap(x: impl Iterator<Item = f64>) -> f64 {
+    let mut x: Vec<_> = x.collect();
+    x.sort_by(f64::total_cmp);
+    let gap = x
+        .windows(2)
+        .map(|x| x[1] - x[0])
+        .filter(|dx| *dx > 0.)
+        .fold(f64::INFINITY, f64::min);
+    if gap.is_finite() {
+        gap
+    } else {
+        1.
+    }
+}
+/// Formats a scale factor as `×10ⁿ` for powers of ten and as `×factor` otherwise
+fn multiplier(scale_factor: f64) -> String {
+    let exponent = scale_factor.log10().round();
+    if scale_factor > 0. && 10f64.powf(exponent) == scale_factor {
+        let exponent: String = format!("{}", exponent as i32)
+            .chars()
+            .map(|c| match c {
+                '-' => '⁻',
+                '0' => '⁰',
+                '1' => '¹',
+                '2' => '²',
+                '3' => '³',
+                '4' => '⁴',
+                '5' => '⁵',
+                '6' => '⁶',
+                '7' => '⁷',
+                '8' => '⁸',
+                '9' => '⁹',
+                c => unreachable!("{} is not a character of an integer", c),
+            })
+            .collect();
+        format!("×10{}", exponent)
+    } else {
+        format!("×{}", scale_factor)
+    }
 }
 /// Colorbar properties
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Colorbar {
-    cmap: colorous::Gradient,
+    cmap: Option<colorous::Gradient>,
     label: Option<String>,
     range: Option<Range<f64>>,
+    ticks: Option<usize>,
+    log: bool,
 }
-impl Default for Colorbar {
-    fn default() -> Self {
+impl Colorbar {
+    /// Creates a new colorbar
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Sets the colorbar colormap, the default is the colormap of the graph
+    ///
+    /// ```
+    /// let n = 64;
+    /// let data: Vec<f64> = (0..n * n).map(|k| (k % n) as f64).collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (n, n)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("colorbar_colormap.png")
+    ///             .colormap(colorous::VIRIDIS)
+    ///             .colorbar(complot::Colorbar::new().colormap(colorous::GREYS)),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn colormap(self, cmap: colorous::Gradient) -> Self {
         Self {
-            cmap: colorous::VIRIDIS,
-            label: None,
-            range: None,
+            cmap: Some(cmap),
+            ..self
+        }
+    }
+    /// Sets the colorbar label
+    pub fn label<S>(self, label: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            label: Some(label.into()),
+            ..self
+        }
+    }
+    /// Sets the colorbar range
+    ///
+    /// The range also sets the colormap bounds of [`Heatmap`], [`ScatterHeatmap`], [`ColorScatter`]
+    /// and of the [`tri`] graphs if [`Config::cmap_minmax`] is not set
+    /// ```
+    /// let n = 64;
+    /// let data: Vec<f64> = (0..n * n).map(|k| (k % n) as f64).collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (n, n)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("colorbar_range.png")
+    ///             .colorbar(complot::Colorbar::new().label("value").range(0f64..100f64)),
+    ///     ),
+    /// )
+    ///     .into();
+    /// let _: complot::ColorScatter = (
+    ///     (0..100).map(|k| {
+    ///         let x = k as f64 / 10.;
+    ///         (x, x.sin(), x)
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("colorbar_range_scatter.png")
+    ///             .colorbar(complot::Colorbar::new().range(0f64..20f64)),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn range(self, range: Range<f64>) -> Self {
+        Self {
+            range: Some(range),
+            ..self
+        }
+    }
+    /// Sets the number of tick labels on the colorbar axis
+    ///
+    /// ```
+    /// let n = 64;
+    /// let data: Vec<f64> = (0..n * n).map(|k| (k % n) as f64).collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (n, n)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("colorbar_ticks.svg")
+    ///             .colorbar(complot::Colorbar::new().range(0f64..80f64).ticks(5)),
+    ///     ),
+    /// )
+    ///     .into();
+    /// // the colorbar tick labels are the only texts of the heatmap
+    /// let svg = std::fs::read_to_string("colorbar_ticks.svg").unwrap();
+    /// assert_eq!(svg.matches("<text").count(), 5);
+    /// ```
+    pub fn ticks(self, ticks: usize) -> Self {
+        Self {
+            ticks: Some(ticks),
+            ..self
         }
     }
+    /// Maps the values onto the colormap in log10 space and draws the colorbar axis on a log10 scale
+    ///
+    /// Only the positive values set the colormap bounds and the non-positive values get the bottom color
+    /// ```
+    /// let n = 64;
+    /// let data: Vec<f64> = (0..n * n).map(|k| 10f64.powf((k % n) as f64 / 8.)).collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (n, n)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("colorbar_log.png")
+    ///             .over_sampling_factor(8)
+    ///             .colorbar(complot::Colorbar::new().label("value").log()),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn log(self) -> Self {
+        Self { log: true, ..self }
+    }
 }
 /// Graph properties
 #[derive(Clone, Debug)]
@@ -152,11 +902,76 @@ pub struct Config {
     title: Option<String>,
     xaxis: Axis,
     yaxis: Axis,
+    secondary_yaxis: Option<Axis>,
+    xscale: Scale,
+    yscale: Scale,
     cmap: colorous::Gradient,
-    cmap_minmax: Option<(f64, f64)>,
+    cmap_min: Option<f64>,
+    cmap_max: Option<f64>,
     colorbar: Option<Colorbar>,
     osf: usize,
     legend: Option<Vec<String>>,
+    also_write: Vec<String>,
+    hist_mode: HistMode,
+    draw_callback: Option<DrawCallback>,
+    chart_callback: Option<ChartCallback>,
+    require_monotonic_x: bool,
+    sort_x: bool,
+    auto_open: bool,
+    size: Option<(u32, u32)>,
+    line_width: u32,
+    line_style: LineStyle,
+    line_mode: LineMode,
+    colors: Option<Vec<(u8, u8, u8)>>,
+    bar_width: f64,
+    bins: Option<usize>,
+    bin_width: Option<f64>,
+    margin: Option<u32>,
+    label_area: Option<(u32, u32)>,
+    grid: bool,
+    grid_style: Option<((u8, u8, u8), f64)>,
+    marker: MarkerShape,
+    marker_size: u32,
+    donut: Option<f64>,
+    background: Option<(u8, u8, u8)>,
+    format: Option<ImageFormat>,
+    fill_style: Option<((u8, u8, u8), f64)>,
+    center_line: bool,
+    range_padding: Option<f64>,
+    grid_resolution: (usize, usize),
+    aggregation: Aggregation,
+    no_data_color: (u8, u8, u8),
+    hlines: Vec<(f64, Option<String>)>,
+    vlines: Vec<(f64, Option<String>)>,
+    annotations: Vec<(f64, f64, String)>,
+    annotation_style: (u32, (u8, u8, u8)),
+    font: Option<String>,
+    title_font_size: Option<u32>,
+    label_font_size: Option<u32>,
+    tick_font_size: Option<u32>,
+    equal_aspect: bool,
+    origin: Origin,
+    orientation: Orientation,
+    categories: Option<Vec<String>>,
+    minor_gridlines: Option<usize>,
+    colorbar_position: ColorbarPosition,
+    legend_position: Option<LegendPosition>,
+    max_points: Option<usize>,
+    cmap_lut_size: usize,
+    nan_color: (u8, u8, u8),
+    scale: f64,
+    markers: bool,
+    bubble_scale: Option<f64>,
+    symmetric_y: bool,
+    rolling_mean: Option<usize>,
+    clip: bool,
+    color_map: HashMap<usize, (u8, u8, u8)>,
+    subtitle: Option<String>,
+    title_align: TitleAlign,
+    cell_labels: bool,
+    cell_label_format: Option<fn(&f64) -> String>,
+    normalize_rows: bool,
+    min_max_markers: bool,
 }
 impl Default for Config {
     fn default() -> Self {
@@ -165,11 +980,76 @@ impl Default for Config {
             title: None,
             xaxis: Default::default(),
             yaxis: Default::default(),
+            xscale: Scale::default(),
+            yscale: Scale::default(),
             cmap: colorous::VIRIDIS,
-            cmap_minmax: None,
+            cmap_min: None,
+            cmap_max: None,
             colorbar: None,
             osf: 2,
             legend: None,
+            also_write: Vec::new(),
+            hist_mode: HistMode::default(),
+            draw_callback: None,
+            chart_callback: None,
+            require_monotonic_x: false,
+            sort_x: false,
+            auto_open: false,
+            size: None,
+            line_width: 1,
+            line_style: LineStyle::default(),
+            line_mode: LineMode::default(),
+            colors: None,
+            bar_width: 0.8,
+            bins: None,
+            bin_width: None,
+            margin: None,
+            label_area: None,
+            grid: true,
+            grid_style: None,
+            marker: MarkerShape::default(),
+            marker_size: 3,
+            donut: None,
+            background: Some((255, 255, 255)),
+            format: None,
+            secondary_yaxis: None,
+            fill_style: None,
+            center_line: false,
+            range_padding: None,
+            grid_resolution: (50, 50),
+            aggregation: Aggregation::default(),
+            no_data_color: (211, 211, 211),
+            hlines: Vec::new(),
+            vlines: Vec::new(),
+            annotations: Vec::new(),
+            annotation_style: (12, (0, 0, 0)),
+            font: None,
+            title_font_size: None,
+            label_font_size: None,
+            tick_font_size: None,
+            equal_aspect: false,
+            origin: Origin::default(),
+            orientation: Orientation::default(),
+            categories: None,
+            minor_gridlines: None,
+            colorbar_position: ColorbarPosition::default(),
+            legend_position: None,
+            max_points: None,
+            cmap_lut_size: 256,
+            nan_color: (211, 211, 211),
+            scale: 1.,
+            markers: false,
+            bubble_scale: None,
+            symmetric_y: false,
+            rolling_mean: None,
+            clip: true,
+            color_map: HashMap::new(),
+            subtitle: None,
+            title_align: TitleAlign::default(),
+            cell_labels: false,
+            cell_label_format: None,
+            normalize_rows: false,
+            min_max_markers: false,
         }
     }
 }
@@ -179,6 +1059,20 @@ impl Config {
         Default::default()
     }
     /// Sets the filename to save the graph to
+    ///
+    /// Line and scatter plots are written as bitmaps if the extension is `png`, `jpg`, `jpeg` or `bmp`
+    /// and as SVG otherwise
+    /// ```
+    /// let _: complot::Scatter = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o.cos(), vec![o.sin()])
+    ///     }),
+    ///     Some(complot::Config::new().filename("scatter.png")),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read("scatter.png").unwrap().starts_with(b"\x89PNG"));
+    /// ```
     pub fn filename<T>(self, filename: T) -> Self
     where
         T: Into<String>,
@@ -188,7 +1082,311 @@ impl Config {
             ..self
         }
     }
+    /// Sets the figure width and height in pixels
+    ///
+    /// Each graph type has its own default size
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///     Some(complot::Config::new().filename("size.svg").size(400, 300)),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("size.svg")
+    ///     .unwrap()
+    ///     .contains(r#"width="400" height="300""#));
+    /// ```
+    pub fn size(self, width: u32, height: u32) -> Self {
+        Self {
+            size: Some((width, height)),
+            ..self
+        }
+    }
+    /// Sets the figure width and height in centimeters for a given resolution in dots per inch
+    ///
+    /// The size in pixels is rounded to the nearest integer,
+    /// the font sizes and line widths are not magnified with the resolution, see [`Config::scale`] for that
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///     Some(complot::Config::new().filename("size_cm.svg").size_cm(8.5, 6., 150.)),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("size_cm.svg")
+    ///     .unwrap()
+    ///     .contains(r#"width="502" height="354""#));
+    /// ```
+    pub fn size_cm(self, width_cm: f64, height_cm: f64, dpi: f64) -> Self {
+        let pixels = |cm: f64| (cm / 2.54 * dpi).round().max(1.) as u32;
+        self.size(pixels(width_cm), pixels(height_cm))
+    }
+    /// Sets the margin in pixels around the chart
+    pub fn margin(self, margin: u32) -> Self {
+        Self {
+            margin: Some(margin),
+            ..self
+        }
+    }
+    /// Sets the sizes in pixels of the left and bottom areas of the axes labels
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![1e6 * (k as f64).sqrt()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("label_area.svg")
+    ///             .margin(20)
+    ///             .label_area(90, 40),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn label_area(self, left: u32, bottom: u32) -> Self {
+        Self {
+            label_area: Some((left, bottom)),
+            ..self
+        }
+    }
+    /// Draws or not the grid lines, the default is to draw them
+    pub fn grid(self, grid: bool) -> Self {
+        Self { grid, ..self }
+    }
+    /// Sets the color and the opacity of the grid lines
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("grid_style.svg")
+    ///             .grid_style((0, 0, 255), 0.1),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn grid_style(self, color: (u8, u8, u8), alpha: f64) -> Self {
+        Self {
+            grid_style: Some((color, alpha)),
+            ..self
+        }
+    }
+    /// Sets the maximum number of minor grid lines between two consecutive major grid lines,
+    /// 0 removes them
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("minor_gridlines.svg")
+    ///             .minor_gridlines(4),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn minor_gridlines(self, n: usize) -> Self {
+        Self {
+            minor_gridlines: Some(n),
+            ..self
+        }
+    }
+    /// Sets the color and the transparency, between 0 and 1, of the filled regions of a [`Band`]
+    pub fn fill_style(self, color: (u8, u8, u8), alpha: f64) -> Self {
+        Self {
+            fill_style: Some((color, alpha)),
+            ..self
+        }
+    }
+    /// Sets the fraction of the data range added on each side of the axis ranges derived from the data
+    ///
+    /// The ranges set with [`Axis::range`] are not padded,
+    /// the default is no padding
+    /// ```
+    /// let _: complot::Scatter = (
+    ///     (0..=10).map(|k| (k as f64, vec![(k * k) as f64])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("range_padding.svg")
+    ///             .range_padding(0.05),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn range_padding(self, padding: f64) -> Self {
+        Self {
+            range_padding: Some(padding.max(0.)),
+            ..self
+        }
+    }
+    /// Draws dashed horizontal reference lines across the chart at the y values
+    ///
+    /// The reference lines are drawn after the series of [`Plot`], [`Scatter`] and [`Combo`]
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("reference-lines.svg")
+    ///             .hline(vec![0.])
+    ///             .vline(vec![std::f64::consts::PI])
+    ///             .labeled_hline(0.5, "threshold"),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn hline(self, values: Vec<f64>) -> Self {
+        let mut hlines = self.hlines;
+        hlines.extend(values.into_iter().map(|value| (value, None)));
+        Self { hlines, ..self }
+    }
+    /// Draws dashed vertical reference lines across the chart at the x values
+    pub fn vline(self, values: Vec<f64>) -> Self {
+        let mut vlines = self.vlines;
+        vlines.extend(values.into_iter().map(|value| (value, None)));
+        Self { vlines, ..self }
+    }
+    /// Draws a dashed horizontal reference line at the y value with its label in the legend
+    pub fn labeled_hline<S: Into<String>>(self, value: f64, label: S) -> Self {
+        let mut hlines = self.hlines;
+        hlines.push((value, Some(label.into())));
+        Self { hlines, ..self }
+    }
+    /// Draws a dashed vertical reference line at the x value with its label in the legend
+    pub fn labeled_vline<S: Into<String>>(self, value: f64, label: S) -> Self {
+        let mut vlines = self.vlines;
+        vlines.push((value, Some(label.into())));
+        Self { vlines, ..self }
+    }
+    /// Writes the texts at the data coordinates `(x, y)` of [`Plot`], [`Scatter`] and [`Combo`] charts
+    ///
+    /// The annotations outside the axis ranges are skipped
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let x = k as f64 / 10.;
+    ///         (x, vec![(-(x - 5.) * (x - 5.)).exp()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("annotations.svg")
+    ///             .annotations(vec![(5., 1., "peak".to_string()), (20., 0., "hidden".to_string())])
+    ///             .annotation_style(16, (255, 0, 0)),
+    ///     ),
+    /// )
+    ///     .into();
+    /// let svg = std::fs::read_to_string("annotations.svg").unwrap();
+    /// assert!(svg.contains("peak") && !svg.contains("hidden"));
+    /// ```
+    pub fn annotations(self, annotations: Vec<(f64, f64, String)>) -> Self {
+        Self {
+            annotations,
+            ..self
+        }
+    }
+    /// Sets the font size and the color of the annotations, the default is 12 and black
+    pub fn annotation_style(self, size: u32, color: (u8, u8, u8)) -> Self {
+        Self {
+            annotation_style: (size, color),
+            ..self
+        }
+    }
+    /// Expands the x or the y axis range so both axes have the same scale
+    ///
+    /// Applies to the linear axes of [`Plot`], [`Scatter`] and the [`tri`] graphs
+    /// ```
+    /// let _: complot::Scatter = (
+    ///     (0..100).map(|k| {
+    ///         let o = 2. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o.cos(), vec![o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("equal-aspect.svg")
+    ///             .size(768, 384)
+    ///             .equal_aspect(true),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn equal_aspect(self, equal_aspect: bool) -> Self {
+        Self {
+            equal_aspect,
+            ..self
+        }
+    }
+    /// Draws the center curve of a [`Band`]
+    pub fn center_line(self, center_line: bool) -> Self {
+        Self {
+            center_line,
+            ..self
+        }
+    }
+    /// Sets the image format of the graph files, regardless of the filename extensions
+    ///
+    /// Otherwise the format is selected from the filename extension.
+    /// The format also applies to the graphs rendered into a buffer with
+    /// [`Plot::write_to`] or [`Heatmap::write_to`].
+    /// PNG and JPEG encoding requires the `png` feature
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("quality.jpg")
+    ///             .format(complot::ImageFormat::Jpeg { quality: 50 }),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::path::Path::new("quality.jpg").exists());
+    /// ```
+    /// and into a buffer
+    /// ```
+    /// fn main() -> complot::Result<()> {
+    ///     let mut jpeg = Vec::new();
+    ///     complot::Plot::write_to(
+    ///         (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///         Some(complot::Config::new().format(complot::ImageFormat::Jpeg { quality: 50 })),
+    ///         &mut jpeg,
+    ///     )?;
+    ///     assert!(jpeg.starts_with(&[0xff, 0xd8]));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn format(self, format: ImageFormat) -> Self {
+        Self {
+            format: Some(format),
+            ..self
+        }
+    }
+    /// Sets the background color, the default is white
+    ///
+    /// `None` leaves the background of SVG graphs transparent,
+    /// bitmaps do not support transparency and are filled in white instead
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///     Some(complot::Config::new().filename("transparent.svg").background(None)),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn background(self, background: Option<(u8, u8, u8)>) -> Self {
+        Self { background, ..self }
+    }
     /// Sets the graph title
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin()])
+    ///     }),
+    ///     complot::complot!("title.svg", xlabel = "x", ylabel = "y", title = "Sine"),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("title.svg").unwrap().contains("Sine"));
+    /// ```
     pub fn title<S>(self, title: S) -> Self
     where
         S: Into<String>,
@@ -198,7 +1396,108 @@ impl Config {
             ..self
         }
     }
+    /// Sets the subtitle drawn below the title in a smaller font
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("subtitle.svg")
+    ///             .title("Sine")
+    ///             .subtitle("5 half periods sampled at 100 points")
+    ///             .title_align(complot::TitleAlign::Left),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("subtitle.svg").unwrap().contains("half periods"));
+    /// ```
+    pub fn subtitle<S>(self, subtitle: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            subtitle: Some(subtitle.into()),
+            ..self
+        }
+    }
+    /// Sets the horizontal alignment of the title and of the subtitle, the default is [`TitleAlign::Center`]
+    pub fn title_align(self, title_align: TitleAlign) -> Self {
+        Self {
+            title_align,
+            ..self
+        }
+    }
+    /// Sets the font family of the texts, the default is `sans-serif`
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("font.svg")
+    ///             .title("Sine")
+    ///             .xaxis(complot::Axis::new().label("x"))
+    ///             .font("serif")
+    ///             .title_font_size(24)
+    ///             .label_font_size(16)
+    ///             .tick_font_size(14),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("font.svg").unwrap().contains("font-family=\"serif\""));
+    /// ```
+    pub fn font<S: Into<String>>(self, family: S) -> Self {
+        Self {
+            font: Some(family.into()),
+            ..self
+        }
+    }
+    /// Sets the font size of the title, the default is 20
+    pub fn title_font_size(self, size: u32) -> Self {
+        Self {
+            title_font_size: Some(size),
+            ..self
+        }
+    }
+    /// Sets the font size of the axis labels, the default is 12
+    pub fn label_font_size(self, size: u32) -> Self {
+        Self {
+            label_font_size: Some(size),
+            ..self
+        }
+    }
+    /// Sets the font size of the tick labels, the default is 12
+    pub fn tick_font_size(self, size: u32) -> Self {
+        Self {
+            tick_font_size: Some(size),
+            ..self
+        }
+    }
     /// Sets the legend
+    ///
+    /// The labels are given to the series in order,
+    /// the series without a matching label are left unlabeled
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin(), o.cos(), o.sin() * o.cos()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("legend.svg")
+    ///             .legend(vec!["sin", "cos"]),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
     pub fn legend<S: Into<String>>(self, legend: Vec<S>) -> Self {
         Self {
             legend: Some(
@@ -210,29 +1509,925 @@ impl Config {
             ..self
         }
     }
-    pub fn over_sampling_factor(self, osf: usize) -> Self {
-        Self { osf, ..self }
+    /// Sets the position of the legend, each graph has its own default position,
+    /// e.g. the upper right corner for [`Plot`] and the middle of the right side for [`Combo`]
+    ///
+    /// [`LegendPosition::Outside`] reserves a margin of 150 pixels on the right of the plotting area
+    /// where the [`Config::legend`] entries, or the labels of the [`Combo`] [`Kind`]s, are listed with the colors of the series
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin(), o.cos()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("legend_position.svg")
+    ///             .legend(vec!["sin", "cos"])
+    ///             .legend_position(complot::LegendPosition::Outside),
+    ///     ),
+    /// )
+    ///     .into();
+    /// let _: complot::Combo = complot::Combo::builder(Some(
+    ///     complot::Config::new()
+    ///         .filename("combo-legend-position.svg")
+    ///         .legend_position(complot::LegendPosition::Outside),
+    /// ))
+    /// .plot((0..100).map(|k| (k as f64, vec![(k as f64 / 10.).sin()])), Some("sin"))
+    /// .scatter((0..20).map(|k| (5. * k as f64, vec![0.])), None)
+    /// .plot((0..100).map(|k| (k as f64, vec![(k as f64 / 10.).cos()])), Some("cos"))
+    /// .build();
+    /// let svg = std::fs::read_to_string("combo-legend-position.svg").unwrap();
+    /// assert!(svg.contains("sin") && svg.contains("cos"));
+    /// ```
+    pub fn legend_position(self, legend_position: LegendPosition) -> Self {
+        Self {
+            legend_position: Some(legend_position),
+            ..self
+        }
     }
-    /// Sets the colormap upper and lower bounds
-    pub fn cmap_minmax(self, cmap_minmax: (f64, f64)) -> Self {
+    /// Sets additional filenames the graph is also written to
+    ///
+    /// The backend of each file is selected from the file extension:
+    /// bitmap for `.png`, `.jpg`, `.jpeg` and `.bmp` and SVG otherwise.
+    /// All the graphs written to a file support it, including [`Combo`] and the [`tri`] graphs,
+    /// but not the graphs drawn on a drawing area supplied by the caller or written to a writer
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("out.svg")
+    ///             .also_write(vec!["out.png"]),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("out.svg").unwrap().starts_with("<svg"));
+    /// assert!(std::fs::read("out.png").unwrap().starts_with(b"\x89PNG"));
+    /// let _: complot::tri::Mesh = (
+    ///     vec![vec![(0., 0.), (1., 0.), (0., 1.)]].into_iter(),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("tri-out.svg")
+    ///             .also_write(vec!["tri-out.png"]),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read("tri-out.png").unwrap().starts_with(b"\x89PNG"));
+    /// ```
+    pub fn also_write<S: Into<String>>(self, filenames: Vec<S>) -> Self {
         Self {
-            cmap_minmax: Some(cmap_minmax),
+            also_write: filenames.into_iter().map(|x| x.into()).collect(),
             ..self
         }
     }
-    /// Sets the x-axis properties
-    pub fn xaxis(self, xaxis: Axis) -> Self {
-        Self { xaxis, ..self }
+    /// Sets how the histograms of several groups of samples are combined
+    pub fn hist_mode(self, hist_mode: HistMode) -> Self {
+        Self { hist_mode, ..self }
     }
-    /// Sets the y-axis properties
-    pub fn yaxis(self, yaxis: Axis) -> Self {
-        Self { yaxis, ..self }
+    /// Sets a callback invoked after the graph is drawn and before it is written to file
+    ///
+    /// ```
+    /// use std::time::{SystemTime, UNIX_EPOCH};
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("timestamp.svg")
+    ///             .draw_callback(|overlay: &dyn complot::Overlay| {
+    ///                 let timestamp = SystemTime::now()
+    ///                     .duration_since(UNIX_EPOCH)?
+    ///                     .as_secs();
+    ///                 let (width, height) = overlay.size();
+    ///                 overlay.text(
+    ///                     &format!("t={}", timestamp),
+    ///                     (width as i32 - 120, height as i32 - 15),
+    ///                     12.,
+    ///                     (128, 128, 128),
+    ///                 )?;
+    ///                 Ok(())
+    ///             }),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("timestamp.svg").unwrap().contains("t="));
+    /// ```
+    pub fn draw_callback<F>(self, callback: F) -> Self
+    where
+        F: Fn(&dyn Overlay) -> CallbackResult + Send + Sync + 'static,
+    {
+        Self {
+            draw_callback: Some(DrawCallback(Arc::new(callback))),
+            ..self
+        }
     }
-    /// Sets the x and y axes to the same axis properties
-    pub fn axes(self, axis: Axis) -> Self {
+    /// Sets a callback invoked with the chart once the mesh and the series are drawn
+    ///
+    /// The callback draws in the chart coordinates
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("chart_callback.svg")
+    ///             .chart_callback(|chart: &mut dyn complot::ChartOverlay| {
+    ///                 let y = chart.y_range();
+    ///                 chart.line(&[(std::f64::consts::PI, y.start), (std::f64::consts::PI, y.end)], (255, 0, 0))?;
+    ///                 chart.text("x=pi", (std::f64::consts::PI, 0.), 14., (255, 0, 0))?;
+    ///                 Ok(())
+    ///             }),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::read_to_string("chart_callback.svg").unwrap().contains("x=pi"));
+    /// ```
+    pub fn chart_callback<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut dyn ChartOverlay) -> CallbackResult + Send + Sync + 'static,
+    {
         Self {
-            xaxis: axis.clone(),
-            yaxis: axis,
+            chart_callback: Some(ChartCallback(Arc::new(callback))),
+            ..self
+        }
+    }
+    /// Fails line plots whose x values are not sorted in increasing order
+    ///
+    /// ```
+    /// let unsorted = vec![(0., vec![0.]), (2., vec![1.]), (1., vec![2.])];
+    /// let result = complot::Plot::build(
+    ///     unsorted.clone().into_iter(),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("unsorted.svg")
+    ///             .require_monotonic_x(true),
+    ///     ),
+    /// );
+    /// assert!(matches!(result, Err(complot::Error::UnsortedX { index: 2 })));
+    /// // without the requirement, the unsorted data is plotted
+    /// let plot = complot::Plot::build(
+    ///     unsorted.into_iter(),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("unsorted.svg")
+    ///             .require_monotonic_x(false),
+    ///     ),
+    /// )
+    /// .unwrap();
+    /// assert!(std::path::Path::new(plot.path()).exists());
+    /// ```
+    pub fn require_monotonic_x(self, require_monotonic_x: bool) -> Self {
+        Self {
+            require_monotonic_x,
+            ..self
+        }
+    }
+    /// Magnifies the bitmap graphs by `scale`, the fonts and the line widths included,
+    /// for high resolution prints without changing the layout
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("scale.png")
+    ///             .title("x2")
+    ///             .scale(2.),
+    ///     ),
+    /// )
+    ///     .into();
+    /// let (width, height) = image::image_dimensions("scale.png").unwrap();
+    /// assert_eq!((width, height), (1536, 1024));
+    /// ```
+    pub fn scale(self, scale: f64) -> Self {
+        Self {
+            scale: if scale > 0. { scale } else { 1. },
+            ..self
+        }
+    }
+    /// Sorts the data of line plots in increasing order of x before drawing
+    pub fn sort_x(self, sort_x: bool) -> Self {
+        Self { sort_x, ..self }
+    }
+    /// Decimates the data of line plots with more than `max_points` x values before drawing
+    ///
+    /// The x values are grouped into `max_points / 2` buckets and, in each bucket, only the points
+    /// with the smallest and the largest values of each series are kept
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..200_000).map(|k| {
+    ///         let x = k as f64 / 200_000.;
+    ///         (x, vec![(2000. * x).sin() * x])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("max_points.svg")
+    ///             .max_points(2000),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::fs::metadata("max_points.svg").unwrap().len() < 100_000);
+    /// ```
+    pub fn max_points(self, max_points: usize) -> Self {
+        Self {
+            max_points: Some(max_points.max(2)),
+            ..self
+        }
+    }
+    /// Overlays, on line plots, the centered moving average of each series over `window` points
+    ///
+    /// The raw data are drawn with a lighter color and the moving averages with the color of the series,
+    /// the windows shrink at both ends of the series and the NaN values are ignored
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..500).map(|k| {
+    ///         let x = k as f64 / 50.;
+    ///         (x, vec![x.sin() + 0.3 * (37. * x).sin() * (91. * x).cos()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("rolling_mean.svg")
+    ///             .legend(vec!["sin(x)"])
+    ///             .rolling_mean(25),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn rolling_mean(self, window: usize) -> Self {
+        Self {
+            rolling_mean: Some(window.max(1)),
+            ..self
+        }
+    }
+    /// Drops the markers outside of the axis ranges and clips the lines at the axis boundaries, default: `true`
+    ///
+    /// The lines are cut where they cross the boundaries instead of skipping the points outside of the ranges
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..200).map(|k| {
+    ///         let x = k as f64 / 20.;
+    ///         (x, vec![x.tan()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("clip.svg")
+    ///             .yaxis(complot::Axis::new().range(-5.0..5.0))
+    ///             .clip(true),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn clip(self, clip: bool) -> Self {
+        Self { clip, ..self }
+    }
+    /// Opens the graph file in the default viewer once it is written
+    ///
+    /// Requires the `opener` feature, the viewer is not launched if the `CI` environment variable is set,
+    /// see [`Config::should_open`]
+    pub fn auto_open(self, auto_open: bool) -> Self {
+        Self { auto_open, ..self }
+    }
+    /// Returns true if the graph files are opened in the default viewer,
+    /// i.e. if [`Config::auto_open`] is set and the `CI` environment variable is not
+    /// ```
+    /// let ci = std::env::var_os("CI").is_some();
+    /// assert!(!complot::Config::new().should_open());
+    /// assert!(!complot::Config::new().auto_open(false).should_open());
+    /// assert_eq!(complot::Config::new().auto_open(true).should_open(), !ci);
+    /// ```
+    pub fn should_open(&self) -> bool {
+        self.auto_open && std::env::var_os("CI").is_none()
+    }
+    /// Sets the number of pixels per heatmap cell along each axis, the factor is at least 1
+    ///
+    /// ```
+    /// let _: complot::Heatmap = (
+    ///     ([1f64].as_slice(), (1, 1)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("single-cell.png")
+    ///             .over_sampling_factor(0),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(std::path::Path::new("single-cell.png").exists());
+    /// ```
+    pub fn over_sampling_factor(self, osf: usize) -> Self {
+        Self {
+            osf: osf.max(1),
+            ..self
+        }
+    }
+    /// Labels the cells of heatmaps with their values, in black or white depending on the brightness of the cells
+    ///
+    /// The integer values are written as such and the others with 2 decimals unless [`Config::cell_label_format`] is set
+    pub fn cell_labels(self, cell_labels: bool) -> Self {
+        Self {
+            cell_labels,
+            ..self
+        }
+    }
+    /// Sets the formatter of the heatmap cell labels, see [`Config::cell_labels`]
+    pub fn cell_label_format(self, format: fn(&f64) -> String) -> Self {
+        Self {
+            cell_label_format: Some(format),
+            ..self
+        }
+    }
+    /// Divides the counts of each row of a [`ConfusionMatrix`] by the row total
+    pub fn normalize_rows(self, normalize_rows: bool) -> Self {
+        Self {
+            normalize_rows,
+            ..self
+        }
+    }
+    /// Sets the colormap of heatmaps
+    pub fn colormap(self, cmap: colorous::Gradient) -> Self {
+        Self { cmap, ..self }
+    }
+    /// Sets the colormap of heatmaps from its name
+    ///
+    /// The name is one of the [colorous](https://docs.rs/colorous) gradients in lower case, e.g. `"inferno"` or `"red_blue"`
+    /// ```
+    /// let n = 101;
+    /// let data: Vec<f64> = (0..n * n).map(|k| ((k % n) as f64 / 10.).sin()).collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (n, n)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("colormap.png")
+    ///             .colormap_named("inferno")
+    ///             .unwrap(),
+    ///     ),
+    /// )
+    ///     .into();
+    /// assert!(complot::Config::new().colormap_named("jet").is_err());
+    /// ```
+    pub fn colormap_named(self, name: &str) -> Result<Self> {
+        use colorous::*;
+        let cmap = match name.to_lowercase().as_str() {
+            "turbo" => TURBO,
+            "viridis" => VIRIDIS,
+            "inferno" => INFERNO,
+            "magma" => MAGMA,
+            "plasma" => PLASMA,
+            "cividis" => CIVIDIS,
+            "warm" => WARM,
+            "cool" => COOL,
+            "cubehelix" => CUBEHELIX,
+            "rainbow" => RAINBOW,
+            "sinebow" => SINEBOW,
+            "spectral" => SPECTRAL,
+            "red_blue" => RED_BLUE,
+            "red_yellow_blue" => RED_YELLOW_BLUE,
+            "blues" => BLUES,
+            "greens" => GREENS,
+            "greys" => GREYS,
+            "oranges" => ORANGES,
+            "purples" => PURPLES,
+            "reds" => REDS,
+            _ => return Err(Error::UnknownColormap(name.to_string())),
+        };
+        Ok(self.colormap(cmap))
+    }
+    /// Sets the number of colors, 256 by default, the colormap of a [`Heatmap`] is sampled at
+    ///
+    /// ```
+    /// let data: Vec<f64> = (0..10_000).map(|k| k as f64).collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (100, 100)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("cmap_lut_size.png")
+    ///             .cmap_lut_size(8),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn cmap_lut_size(self, cmap_lut_size: usize) -> Self {
+        Self {
+            cmap_lut_size: cmap_lut_size.max(2),
+            ..self
+        }
+    }
+    /// Sets the color of the NaN cells of a [`Heatmap`] or of a [`tri::Heatmap`], the default is light gray
+    ///
+    /// ```
+    /// let data: Vec<f64> = (0..400)
+    ///     .map(|k| if k % 7 == 0 { f64::NAN } else { k as f64 })
+    ///     .collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (20, 20)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("nan-color-heatmap.png")
+    ///             .over_sampling_factor(10)
+    ///             .nan_color((255, 0, 255)),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn nan_color(self, nan_color: (u8, u8, u8)) -> Self {
+        Self { nan_color, ..self }
+    }
+    /// Sets the colormap upper and lower bounds
+    pub fn cmap_minmax(self, (cmap_min, cmap_max): (f64, f64)) -> Self {
+        Self {
+            cmap_min: Some(cmap_min),
+            cmap_max: Some(cmap_max),
+            ..self
+        }
+    }
+    /// Sets the colormap lower bound, the upper bound is derived from the data unless [`Config::cmap_max`] is set
+    pub fn cmap_min(self, cmap_min: f64) -> Self {
+        Self {
+            cmap_min: Some(cmap_min),
+            ..self
+        }
+    }
+    /// Sets the colormap upper bound, the lower bound is derived from the data unless [`Config::cmap_min`] is set
+    ///
+    /// ```
+    /// let data: Vec<f64> = (0..40_000)
+    ///     .map(|k| ((k / 200) as f64 / 30.).sin() * ((k % 200) as f64 / 30.).cos())
+    ///     .collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (200, 200)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("cmap_max.png")
+    ///             .cmap_max(0.5),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn cmap_max(self, cmap_max: f64) -> Self {
+        Self {
+            cmap_max: Some(cmap_max),
+            ..self
+        }
+    }
+    /// Sets the position of the first row of a [`Heatmap`], the default is [`Origin::TopLeft`]
+    ///
+    /// ```
+    /// let (rows, cols) = (20, 30);
+    /// // a gradient increasing from the first to the last row
+    /// let data: Vec<f64> = (0..rows * cols).map(|k| (k / cols) as f64).collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (rows, cols)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("heatmap-origin.png")
+    ///             .origin(complot::Origin::BottomLeft),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn origin(self, origin: Origin) -> Self {
+        Self { origin, ..self }
+    }
+    /// Sets the position of the colorbar of a [`Heatmap`], the default is [`ColorbarPosition::Bottom`]
+    ///
+    /// ```
+    /// let data: Vec<f64> = (0..40 * 60)
+    ///     .map(|k| ((k % 60) as f64 / 10.).sin() * ((k / 60) as f64 / 10.).cos())
+    ///     .collect();
+    /// let _: complot::Heatmap = (
+    ///     (data.as_slice(), (40, 60)),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("heatmap-colorbar-right.png")
+    ///             .title("right colorbar")
+    ///             .over_sampling_factor(4)
+    ///             .colorbar_position(complot::ColorbarPosition::Right),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn colorbar_position(self, colorbar_position: ColorbarPosition) -> Self {
+        Self {
+            colorbar_position,
+            ..self
+        }
+    }
+    /// Sets the number of cells along the x and y axes of a [`ScatterHeatmap`], the default is 50x50
+    pub fn grid_resolution(self, nx: usize, ny: usize) -> Self {
+        Self {
+            grid_resolution: (nx.max(1), ny.max(1)),
+            ..self
+        }
+    }
+    /// Sets how the values of the points in the same cell of a [`ScatterHeatmap`] are aggregated
+    pub fn aggregation(self, aggregation: Aggregation) -> Self {
+        Self {
+            aggregation,
+            ..self
+        }
+    }
+    /// Sets the color of the cells of a [`ScatterHeatmap`] without any point, the default is light gray
+    ///
+    /// ```
+    /// let _: complot::ScatterHeatmap = (
+    ///     (0..200).map(|k| {
+    ///         let x = (k as f64 * 0.618).fract();
+    ///         (x, x * x, k as f64)
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("no-data-color.png")
+    ///             .grid_resolution(20, 20)
+    ///             .aggregation(complot::Aggregation::Count)
+    ///             .no_data_color((255, 255, 255)),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn no_data_color(self, no_data_color: (u8, u8, u8)) -> Self {
+        Self {
+            no_data_color,
+            ..self
+        }
+    }
+    /// Sets the x-axis properties
+    pub fn xaxis(self, xaxis: Axis) -> Self {
+        Self { xaxis, ..self }
+    }
+    /// Sets the y-axis properties
+    pub fn yaxis(self, yaxis: Axis) -> Self {
+        Self { yaxis, ..self }
+    }
+    /// Sets the x-axis label, keeping the other x-axis properties
+    pub fn xlabel<S>(self, label: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            xaxis: Axis {
+                label: Some(label.into()),
+                ..self.xaxis
+            },
+            ..self
+        }
+    }
+    /// Sets the y-axis label, keeping the other y-axis properties
+    pub fn ylabel<S>(self, label: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            yaxis: Axis {
+                label: Some(label.into()),
+                ..self.yaxis
+            },
+            ..self
+        }
+    }
+    /// Sets the x-axis range, keeping the other x-axis properties
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("lim.svg")
+    ///             .xaxis(complot::Axis::new().label("x"))
+    ///             .xlim(0.0..std::f64::consts::PI)
+    ///             .ylim(0.0..1.5),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn xlim(self, range: Range<f64>) -> Self {
+        Self {
+            xaxis: Axis {
+                range: Some(range),
+                ..self.xaxis
+            },
+            ..self
+        }
+    }
+    /// Sets the y-axis range, keeping the other y-axis properties
+    pub fn ylim(self, range: Range<f64>) -> Self {
+        Self {
+            yaxis: Axis {
+                range: Some(range),
+                ..self.yaxis
+            },
+            ..self
+        }
+    }
+    /// Sets the properties of the secondary y-axis on the right of a [`TwinPlot`]
+    pub fn secondary_yaxis(self, secondary_yaxis: Axis) -> Self {
+        Self {
+            secondary_yaxis: Some(secondary_yaxis),
+            ..self
+        }
+    }
+    /// Sets the stroke width in pixels of the lines, at least 1
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![(k as f64 / 10.).sin()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("line_width.svg")
+    ///             .line_style(complot::LineStyle::Dotted)
+    ///             .line_width(0),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn line_width(self, line_width: u32) -> Self {
+        Self {
+            line_width: line_width.max(1),
+            ..self
+        }
+    }
+    /// Sets the stroke style of the lines
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin(), o.cos()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("line_style.svg")
+    ///             .line_width(3)
+    ///             .line_style(complot::LineStyle::Dashed),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn line_style(self, line_style: LineStyle) -> Self {
+        Self { line_style, ..self }
+    }
+    /// Sets how consecutive points are joined, e.g. with stairs for piecewise-constant signals
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..20).map(|k| (k as f64, vec![(k as f64 / 3.).sin().round()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("line_mode.svg")
+    ///             .line_mode(complot::LineMode::PostStep),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn line_mode(self, line_mode: LineMode) -> Self {
+        Self { line_mode, ..self }
+    }
+    /// Draws the markers set with [`Config::marker`] and [`Config::marker_size`]
+    /// at the data points of line plots
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..20).map(|k| (k as f64, vec![(k as f64 / 3.).sin()])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("markers.svg")
+    ///             .markers(true),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn markers(self, markers: bool) -> Self {
+        Self { markers, ..self }
+    }
+    /// Marks the smallest and the largest values of a [`Sparkline`] with dots
+    pub fn min_max_markers(self, min_max_markers: bool) -> Self {
+        Self {
+            min_max_markers,
+            ..self
+        }
+    }
+    /// Centers the y-axis range derived from the data of [`Plot`] and [`Scatter`] graphs on zero,
+    /// e.g. for positive and negative deviations to be visually comparable
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| (k as f64, vec![0.1 * (k as f64 / 5.).sin() + 0.05])),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("symmetric_y.svg")
+    ///             .symmetric_y(true),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn symmetric_y(self, symmetric_y: bool) -> Self {
+        Self {
+            symmetric_y,
+            ..self
+        }
+    }
+    /// Sets the shape of the scatter plot markers
+    ///
+    /// ```
+    /// let _: complot::Scatter = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin(), o.cos()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("marker.svg")
+    ///             .marker(complot::MarkerShape::TriangleUp)
+    ///             .marker_size(5),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn marker(self, marker: MarkerShape) -> Self {
+        Self { marker, ..self }
+    }
+    /// Sets the size in pixels, 3 by default, of the markers of [`Scatter`] plots
+    /// and of the scatter series of [`Combo`] plots
+    ///
+    /// ```
+    /// use complot::{Combo, Config, Kind};
+    /// let dots = (0..5000).map(|k| {
+    ///     let x = k as f64 / 5000.;
+    ///     (x, vec![(50. * x).sin() * x])
+    /// });
+    /// let _: Combo = From::<complot::Complot>::from((
+    ///     vec![Box::new(dots)],
+    ///     vec![Kind::Scatter(None)],
+    ///     Some(Config::new().filename("combo-marker-size.svg").marker_size(1)),
+    /// ));
+    /// ```
+    pub fn marker_size(self, marker_size: u32) -> Self {
+        Self {
+            marker_size,
+            ..self
+        }
+    }
+    /// Sets the radius in pixels of the disks of size 1 of [`Bubble`] charts,
+    /// the radius of the other disks scales with the square root of their size
+    pub fn bubble_scale(self, bubble_scale: f64) -> Self {
+        Self {
+            bubble_scale: Some(bubble_scale),
+            ..self
+        }
+    }
+    /// Draws pie charts as donuts with the hole radius given as a fraction, between 0 and 1, of the pie radius
+    pub fn donut(self, hole: f64) -> Self {
+        Self {
+            donut: Some(hole.clamp(0., 1.)),
+            ..self
+        }
+    }
+    /// Sets the colors of the series, in order
+    ///
+    /// The colors are cycled through if there are more series than colors,
+    /// the default colors are the Tableau10 colors, see also [`Config::palette`]
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin(), o.cos()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("colors.svg")
+    ///             .colors(vec![(255, 0, 0), (0, 0, 255)]),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn colors(self, colors: Vec<(u8, u8, u8)>) -> Self {
+        Self {
+            colors: Some(colors),
+            ..self
+        }
+    }
+    /// Sets the colors of some series by index, overriding the colors of [`Config::colors`] or of the palette
+    ///
+    /// The series `k` is drawn with `color_map[&k]` if it is in the map and
+    /// with the entry `k` of the cycled colors otherwise, whatever the colors of the other series,
+    /// so that the same series keeps the same color across plots
+    /// ```
+    /// use std::collections::HashMap;
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, vec![o.sin(), o.cos(), o.sin() * o.cos()])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("color_map.svg")
+    ///             .color_map(HashMap::from([(1, (0, 0, 0))])),
+    ///     ),
+    /// )
+    ///     .into();
+    /// let svg = std::fs::read_to_string("color_map.svg").unwrap();
+    /// // the 2nd series is black and the 3rd one keeps the 3rd Tableau10 color
+    /// assert!(!svg.contains("#F28E2B") && svg.contains("#E15759"));
+    /// ```
+    pub fn color_map(self, color_map: HashMap<usize, (u8, u8, u8)>) -> Self {
+        Self { color_map, ..self }
+    }
+    /// Sets the colors of the series to a colorous categorical scheme
+    ///
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (0..100).map(|k| {
+    ///         let o = 2. * std::f64::consts::PI * k as f64 / 100.;
+    ///         (o, (0..12).map(|i| (o + i as f64 / 4.).sin()).collect())
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("palette.svg")
+    ///             .palette(&colorous::SET3),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn palette(self, palette: &[colorous::Color]) -> Self {
+        self.colors(palette.iter().map(|c| c.as_tuple()).collect())
+    }
+    /// Sets the direction of the bars of a [`Bar`] chart, the default is [`Orientation::Vertical`]
+    pub fn orientation(self, orientation: Orientation) -> Self {
+        Self {
+            orientation,
+            ..self
+        }
+    }
+    /// Sets the fraction, between 0 and 1, of the space between 2 consecutive x values filled by a group of bars
+    pub fn bar_width(self, bar_width: f64) -> Self {
+        Self {
+            bar_width: bar_width.clamp(0., 1.),
+            ..self
+        }
+    }
+    /// Sets the number of bins of histograms, at most 10000
+    ///
+    /// Sturges' rule is used if neither the number of bins nor the [bin width](Config::bin_width) is set
+    pub fn bins(self, bins: usize) -> Self {
+        Self {
+            bins: Some(bins.clamp(1, histogram::MAX_BINS)),
+            ..self
+        }
+    }
+    /// Sets the width of the bins of histograms, overriding [`Config::bins`]
+    ///
+    /// The width is enlarged if the samples would be spread over more than 10000 bins
+    /// ```
+    /// let _: complot::Histogram = (
+    ///     vec![vec![0., 0.5, f64::NAN, 1., f64::INFINITY]].into_iter(),
+    ///     Some(complot::Config::new().filename("bin_width.svg").bin_width(1e-12)),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn bin_width(self, bin_width: f64) -> Self {
+        Self {
+            bin_width: Some(bin_width),
+            ..self
+        }
+    }
+    /// Sets the x-axis scale of line plots
+    ///
+    /// The data is plotted as is, a non-positive value on a logarithmic axis is an error
+    /// ```
+    /// let _: complot::Plot = (
+    ///     (1..100).map(|k| {
+    ///         let x = k as f64;
+    ///         (x, vec![x * x])
+    ///     }),
+    ///     Some(
+    ///         complot::Config::new()
+    ///             .filename("log_scale.svg")
+    ///             .xscale(complot::Scale::Log10)
+    ///             .yscale(complot::Scale::Log10),
+    ///     ),
+    /// )
+    ///     .into();
+    /// ```
+    pub fn xscale(self, xscale: Scale) -> Self {
+        Self { xscale, ..self }
+    }
+    /// Sets the y-axis scale of line plots
+    pub fn yscale(self, yscale: Scale) -> Self {
+        Self { yscale, ..self }
+    }
+    /// Sets the x and y axes to the same axis properties
+    pub fn axes(self, axis: Axis) -> Self {
+        Self {
+            xaxis: axis.clone(),
+            yaxis: axis,
             ..self
         }
     }
@@ -240,28 +2435,1052 @@ impl Config {
     pub fn with_colorbar(self) -> Self {
         if self.colorbar.is_none() {
             Self {
-                colorbar: Default::default(),
+                colorbar: Some(Default::default()),
                 ..self
             }
         } else {
             self
         }
     }
+    /// Sets the colorbar properties
+    pub fn colorbar(self, colorbar: Colorbar) -> Self {
+        Self {
+            colorbar: Some(colorbar),
+            ..self
+        }
+    }
+    /// Sets the axis ranges that are not set yet to the range of all the data
+    /// expanded on each side by the [range padding](Config::range_padding), none by default
+    ///
+    /// ```
+    /// let xy: Vec<_> = (0..=10).map(|k| (k as f64, vec![k as f64 / 10.])).collect();
+    /// let mut config = complot::Config::new().yaxis(complot::Axis::new().range(-1. ..2.));
+    /// config.auto_range(vec![xy.as_slice()]);
+    /// ```
     pub fn auto_range(&mut self, iters: Vec<&[(f64, Vec<f64>)]>) -> &mut Self {
         let mut xrange = f64::INFINITY..f64::NEG_INFINITY;
         let mut yrange = f64::INFINITY..f64::NEG_INFINITY;
-        for iter in &iters {
-            let xy = *iter;
-            let (it_xrange, it_yrange) = Plot::xy_range(&xy);
-            xrange.start = xrange.start.min(it_xrange.start);
-            xrange.end = xrange.end.max(it_xrange.end);
-            yrange.start = yrange.start.min(it_yrange.start);
-            yrange.end = yrange.end.max(it_yrange.end);
-        }
-        self.xaxis = self.xaxis.clone().range(xrange);
-        self.yaxis = self.yaxis.clone().range(yrange);
+        for xy in iters.into_iter().filter(|xy| !xy.is_empty()) {
+            let (x_max, y_max) = Plot::xy_max(xy);
+            let (x_min, y_min) = Plot::xy_min(xy);
+            xrange.start = xrange.start.min(x_min);
+            xrange.end = xrange.end.max(x_max);
+            yrange.start = yrange.start.min(y_min);
+            yrange.end = yrange.end.max(y_max);
+        }
+        let padding = self.range_padding.unwrap_or(0.);
+        if self.xaxis.range.is_none() {
+            self.xaxis.range = Some(padded(xrange, padding));
+        }
+        if self.yaxis.range.is_none() {
+            self.yaxis.range = Some(padded(yrange, padding));
+        }
         self
     }
+    /// Renders the graph `D` into the configured file and into the additional files
+    ///
+    /// `size` is the default figure size of the graph
+    ///
+    /// The backend is selected from the file extension: bitmap for `png`, `jpg`, `jpeg` and `bmp` files
+    /// and SVG otherwise.
+    /// Files without extension are written as PNG with the `png` feature or as SVG otherwise
+    ///
+    /// Returns the path of the configured file
+    fn render<D: Draw>(
+        &self,
+        data: &D::Data,
+        default_filename: &str,
+        size: (u32, u32),
+    ) -> Result<String> {
+        let mut written = String::new();
+        let filename = self
+            .filename
+            .clone()
+            .unwrap_or_else(|| default_filename.to_string());
+        let size = self.size.unwrap_or(size);
+        for (k, filename) in std::iter::once(&filename)
+            .chain(self.also_write.iter())
+            .enumerate()
+        {
+            let mut path = Path::new(filename).to_path_buf();
+            if path.extension().is_none() {
+                path.set_extension(match self.format {
+                    Some(ImageFormat::Svg) => "svg",
+                    Some(ImageFormat::Png) => "png",
+                    Some(ImageFormat::Jpeg { .. }) => "jpg",
+                    None if cfg!(feature = "png") => "png",
+                    None => "svg",
+                });
+            }
+            match (self.format, path.extension().and_then(|x| x.to_str())) {
+                (Some(format @ ImageFormat::Png), _)
+                | (Some(format @ ImageFormat::Jpeg { .. }), _) => {
+                    self.render_encoded::<D, _>(
+                        data,
+                        std::io::BufWriter::new(std::fs::File::create(&path)?),
+                        size,
+                        format,
+                    )?;
+                }
+                (None, Some("png"))
+                | (None, Some("jpg"))
+                | (None, Some("jpeg"))
+                | (None, Some("bmp")) => {
+                    let backend = BitMapBackend::new(&path, self.scaled(size));
+                    if self.scale == 1. {
+                        self.paint::<D, _>(data, &backend.into_drawing_area(), false)?;
+                    } else {
+                        let backend = ScaledBackend::new(backend, self.scale);
+                        self.paint::<D, _>(data, &backend.into_drawing_area(), false)?;
+                    }
+                }
+                (format, extension) => {
+                    if format.is_none() && extension != Some("svg") {
+                        eprintln!(
+                            "Complot: unknown image format for {:?}, writing SVG instead",
+                            path
+                        );
+                    }
+                    let root = SVGBackend::new(&path, size).into_drawing_area();
+                    self.paint::<D, _>(data, &root, true)?;
+                }
+            }
+            if k == 0 {
+                self.open(&path);
+                written = path.to_string_lossy().into_owned();
+            }
+        }
+        Ok(written)
+    }
+    /// Fills the background of `root`, draws the graph `D` of `data` on it and presents it
+    fn paint<D: Draw, DB>(
+        &self,
+        data: &D::Data,
+        root: &DrawingArea<DB, Shift>,
+        transparent: bool,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        self.fill_background(root, transparent)?;
+        self.draw_on::<D, DB>(data, root)?;
+        root.present()?;
+        Ok(())
+    }
+    /// Draws the graph `D` of `data` on `root` followed by the post-draw callback
+    fn draw_on<D: Draw, DB>(&self, data: &D::Data, root: &DrawingArea<DB, Shift>) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        D::draw(data, root, self)?;
+        self.post_draw(root)
+    }
+    /// Returns the size in pixels of the bitmap graphs of size `size` magnified by [`Config::scale`]
+    fn scaled(&self, (width, height): (u32, u32)) -> (u32, u32) {
+        (
+            (width as f64 * self.scale).round() as u32,
+            (height as f64 * self.scale).round() as u32,
+        )
+    }
+    /// Draws the graph `D` of `data` into a bitmap buffer and writes it to `writer` encoded with `format`
+    #[cfg(feature = "png")]
+    fn render_encoded<D: Draw, W: std::io::Write>(
+        &self,
+        data: &D::Data,
+        writer: W,
+        size: (u32, u32),
+        format: ImageFormat,
+    ) -> Result<()> {
+        let size = self.scaled(size);
+        let mut buffer = vec![0u8; size.0 as usize * size.1 as usize * 3];
+        {
+            let backend = BitMapBackend::with_buffer(&mut buffer, size);
+            if self.scale == 1. {
+                self.paint::<D, _>(data, &backend.into_drawing_area(), false)?;
+            } else {
+                let backend = ScaledBackend::new(backend, self.scale);
+                self.paint::<D, _>(data, &backend.into_drawing_area(), false)?;
+            }
+        }
+        match format {
+            ImageFormat::Jpeg { quality } => {
+                image::codecs::jpeg::JpegEncoder::new_with_quality(writer, quality.clamp(1, 100))
+                    .write_image(&buffer, size.0, size.1, image::ColorType::Rgb8)?
+            }
+            _ => image::codecs::png::PngEncoder::new(writer).write_image(
+                &buffer,
+                size.0,
+                size.1,
+                image::ColorType::Rgb8,
+            )?,
+        }
+        Ok(())
+    }
+    /// Encoding PNG and JPEG graphs requires the `png` feature
+    #[cfg(not(feature = "png"))]
+    fn render_encoded<D: Draw, W: std::io::Write>(
+        &self,
+        _data: &D::Data,
+        _writer: W,
+        _size: (u32, u32),
+        format: ImageFormat,
+    ) -> Result<()> {
+        Err(Error::Other(
+            format!("encoding {:?} graphs requires the `png` feature", format).into(),
+        ))
+    }
+    /// Draws the graph `D` of `data` into `writer`, the filenames are ignored
+    ///
+    /// The graph is encoded as PNG or JPEG if set with [`Config::format`] and as SVG otherwise
+    fn render_to_writer<D: Draw, W: std::io::Write>(
+        &self,
+        data: &D::Data,
+        mut writer: W,
+        size: (u32, u32),
+    ) -> Result<()> {
+        match self.format {
+            Some(format @ ImageFormat::Png) | Some(format @ ImageFormat::Jpeg { .. }) => {
+                self.render_encoded::<D, W>(data, writer, self.size.unwrap_or(size), format)
+            }
+            _ => Ok(writer.write_all(self.render_svg_string::<D>(data, size)?.as_bytes())?),
+        }
+    }
+    /// Draws the graph `D` of `data` into an SVG string, the filenames are ignored
+    fn render_svg_string<D: Draw>(&self, data: &D::Data, size: (u32, u32)) -> Result<String> {
+        let size = self.size.unwrap_or(size);
+        let mut svg = String::new();
+        {
+            let root = SVGBackend::with_string(&mut svg, size).into_drawing_area();
+            self.paint::<D, _>(data, &root, true)?;
+        }
+        Ok(svg)
+    }
+    /// Opens `path` in the default viewer if [`Config::auto_open`] is set
+    ///
+    /// Failing to launch the viewer is reported but is not an error
+    fn open<P: AsRef<Path>>(&self, path: P) {
+        if !self.should_open() {
+            return;
+        }
+        #[cfg(feature = "opener")]
+        if let Err(e) = opener::open(path.as_ref()) {
+            eprintln!("Complot failed to open {:?}: {}", path.as_ref(), e);
+        }
+        #[cfg(not(feature = "opener"))]
+        eprintln!(
+            "Complot: opening {:?} requires the `opener` feature",
+            path.as_ref()
+        );
+    }
+    /// Returns the endless cycle of the series colors, where the color of the series `k`
+    /// is taken from [`Config::color_map`] if it is set
+    fn color_cycle(&self) -> impl Iterator<Item = RGBColor> + '_ {
+        (0..).map(move |k| self.series_color(k))
+    }
+    /// Returns the color of the `k`-th series, see [`Config::colors`] and [`Config::color_map`]
+    fn series_color(&self, k: usize) -> RGBColor {
+        if let Some(&(r, g, b)) = self.color_map.get(&k) {
+            return RGBColor(r, g, b);
+        }
+        match &self.colors {
+            Some(colors) if !colors.is_empty() => {
+                let (r, g, b) = colors[k % colors.len()];
+                RGBColor(r, g, b)
+            }
+            _ => {
+                let c = colorous::TABLEAU10[k % colorous::TABLEAU10.len()];
+                RGBColor(c.r, c.g, c.b)
+            }
+        }
+    }
+    /// Fills `root` with the background color
+    ///
+    /// Without background color, `root` is left untouched if the backend is `transparent` or filled in white otherwise
+    fn fill_background<DB>(&self, root: &DrawingArea<DB, Shift>, transparent: bool) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        match self.background {
+            Some((r, g, b)) => root.fill(&RGBColor(r, g, b))?,
+            None if !transparent => root.fill(&WHITE)?,
+            None => (),
+        }
+        Ok(())
+    }
+    /// Returns a chart builder on `root` with the configured margin and label areas
+    /// or with the given defaults
+    fn chart_builder<'a, 'b, DB: DrawingBackend>(
+        &self,
+        root: &'a DrawingArea<DB, Shift>,
+        margin: u32,
+        label_area: (u32, u32),
+    ) -> ChartBuilder<'a, 'b, DB> {
+        let (left, bottom) = self.label_area.unwrap_or(label_area);
+        let mut chart = ChartBuilder::on(root);
+        chart
+            .set_label_area_size(LabelAreaPosition::Left, left)
+            .set_label_area_size(LabelAreaPosition::Bottom, bottom)
+            .margin(self.margin.unwrap_or(margin));
+        if self.legend_position == Some(LegendPosition::Outside) {
+            chart.margin_right(self.margin.unwrap_or(margin) + LEGEND_WIDTH);
+        }
+        chart
+    }
+    /// Adds the title and the subtitle above the chart laid out on `root` with the `margin` given to [`Config::chart_builder`]
+    ///
+    /// A centered title alone is the caption of the chart,
+    /// otherwise the texts are drawn on `root` and the top margin of the chart is enlarged to make room for them
+    fn caption<'a, 'b, DB>(
+        &'b self,
+        root: &'a DrawingArea<DB, Shift>,
+        chart: &mut ChartBuilder<'a, 'b, DB>,
+        margin: u32,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let title = match &self.title {
+            Some(title) => title,
+            None => return Ok(()),
+        };
+        if self.subtitle.is_none() && self.title_align == TitleAlign::Center {
+            chart.caption(title, self.title_font());
+            return Ok(());
+        }
+        let margin = self.margin.unwrap_or(margin);
+        let (x, h_pos) = match self.title_align {
+            TitleAlign::Left => (margin as i32, HPos::Left),
+            TitleAlign::Center => (root.dim_in_pixel().0 as i32 / 2, HPos::Center),
+        };
+        let title_style =
+            TextStyle::from(self.title_font().into_font()).pos(Pos::new(h_pos, VPos::Top));
+        root.draw_text(title, &title_style, (x, 0))?;
+        if let Some(subtitle) = &self.subtitle {
+            let subtitle_style = TextStyle::from(self.subtitle_font().into_font())
+                .color(&RGBColor(80, 80, 80))
+                .pos(Pos::new(h_pos, VPos::Top));
+            let (_, height) = root.estimate_text_size(title, &title_style)?;
+            root.draw_text(subtitle, &subtitle_style, (x, height as i32 + 4))?;
+        }
+        chart.margin_top(margin + self.title_height(root)?);
+        Ok(())
+    }
+    /// Returns the height of the title block laid out by [`Config::caption`] above the chart margin:
+    /// the plotters caption of a centered title alone or the title, a 4 pixels gap and the subtitle
+    fn title_height<DB>(&self, root: &DrawingArea<DB, Shift>) -> Result<u32>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let title = match &self.title {
+            Some(title) => title,
+            None => return Ok(0),
+        };
+        let (_, height) =
+            root.estimate_text_size(title, &TextStyle::from(self.title_font().into_font()))?;
+        Ok(match &self.subtitle {
+            // the caption layout of ChartBuilder::build_cartesian_2d
+            None if self.title_align == TitleAlign::Center => 2 * (height / 2).min(5) + height,
+            None => height,
+            Some(subtitle) => {
+                let style = TextStyle::from(self.subtitle_font().into_font());
+                height + 4 + root.estimate_text_size(subtitle, &style)?.1
+            }
+        })
+    }
+    /// Draws the legend of `chart` at the `default` position unless [`Config::legend_position`] is set
+    ///
+    /// The legend outside of the plotting area is drawn on `root`, in the margin reserved
+    /// by [`Config::chart_builder`], with the [`Config::legend`] entries and the series colors
+    fn draw_legend<'a, DB, CT>(
+        &self,
+        root: &DrawingArea<DB, Shift>,
+        chart: &mut ChartContext<'a, DB, CT>,
+        default: SeriesLabelPosition,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend + 'a,
+        DB::ErrorType: 'static,
+        CT: CoordTranslate,
+    {
+        let position = match self.legend_position {
+            None => default,
+            Some(LegendPosition::UpperLeft) => SeriesLabelPosition::UpperLeft,
+            Some(LegendPosition::UpperRight) => SeriesLabelPosition::UpperRight,
+            Some(LegendPosition::LowerLeft) => SeriesLabelPosition::LowerLeft,
+            Some(LegendPosition::LowerRight) => SeriesLabelPosition::LowerRight,
+            Some(LegendPosition::Outside) => {
+                // plotters clips the series labels to the plotting area
+                let (x0, y0) = chart.plotting_area().get_base_pixel();
+                let (width, _) = chart.plotting_area().dim_in_pixel();
+                let (rx, ry) = root.get_base_pixel();
+                let (x, y) = (x0 - rx + width as i32 + 10, y0 - ry);
+                let font = (self.font_family(), 12).into_font();
+                for (k, (label, color)) in self
+                    .legend
+                    .iter()
+                    .flatten()
+                    .zip(self.color_cycle())
+                    .filter(|(label, _)| !label.is_empty())
+                    .enumerate()
+                {
+                    root.draw(
+                        &(EmptyElement::at((x, y + 16 * k as i32))
+                            + Rectangle::new([(0, 2), (20, 10)], color.filled())
+                            + Text::new(label.clone(), (25, 0), font.clone())),
+                    )?;
+                }
+                return Ok(());
+            }
+        };
+        chart
+            .configure_series_labels()
+            .border_style(BLACK)
+            .background_style(WHITE.mix(0.8))
+            .position(position)
+            .draw()?;
+        Ok(())
+    }
+    /// Returns the font family of the texts
+    fn font_family(&self) -> &str {
+        self.font.as_deref().unwrap_or("sans-serif")
+    }
+    /// Returns the font of the title
+    fn title_font(&self) -> (&str, u32) {
+        (self.font_family(), self.title_font_size.unwrap_or(20))
+    }
+    /// Returns the font of the subtitle
+    fn subtitle_font(&self) -> (&str, u32) {
+        let (family, size) = self.title_font();
+        (family, (size * 7 / 10).max(1))
+    }
+    /// Returns the axis ranges with either range expanded about its center
+    /// for the x and y axes to have the same scale on the plotting area of
+    /// the chart given by [`Config::chart_builder`] if [`Config::equal_aspect`] is set
+    fn equal_aspect_ranges<DB>(
+        &self,
+        root: &DrawingArea<DB, Shift>,
+        margin: u32,
+        label_area: (u32, u32),
+        (xrange, yrange): (Range<f64>, Range<f64>),
+    ) -> Result<(Range<f64>, Range<f64>)>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if !self.equal_aspect {
+            return Ok((xrange, yrange));
+        }
+        // the plotting area is laid out the same way as in ChartBuilder::build_cartesian_2d
+        let margin = 2 * self.margin.unwrap_or(margin) as i64;
+        let (left, bottom) = self.label_area.unwrap_or(label_area);
+        let (width, height) = root.dim_in_pixel();
+        let title_height = self.title_height(root)? as i64;
+        let legend = match self.legend_position {
+            Some(LegendPosition::Outside) => LEGEND_WIDTH as i64,
+            _ => 0,
+        };
+        let width = (width as i64 - margin - legend - left as i64).max(1) as f64;
+        let height = (height as i64 - margin - bottom as i64 - title_height).max(1) as f64;
+        let (dx, dy) = (xrange.end - xrange.start, yrange.end - yrange.start);
+        let expand = |range: Range<f64>, width: f64| {
+            let center = 0.5 * (range.start + range.end);
+            center - 0.5 * width..center + 0.5 * width
+        };
+        Ok(if dx / width > dy / height {
+            (xrange, expand(yrange, dx * height / width))
+        } else {
+            (expand(xrange, dy * width / height), yrange)
+        })
+    }
+    /// Returns the y-axis range derived from the data, `range`, centered on zero
+    /// if [`Config::symmetric_y`] is set and the axis is linear
+    fn symmetric_y_range(&self, range: Range<f64>, scale: Scale) -> Range<f64> {
+        match scale {
+            Scale::Linear if self.symmetric_y => {
+                let m = range.start.abs().max(range.end.abs());
+                -m..m
+            }
+            _ => range,
+        }
+    }
+    /// Pads the axis range derived from the data with the range padding, in log space for log10 axes,
+    /// a single value is centered in a range of width 1 (a decade on log10 axes)
+    fn pad_range(&self, range: Range<f64>, scale: Scale) -> Range<f64> {
+        let padding = self.range_padding.unwrap_or(0.);
+        if padding == 0. && range.end > range.start {
+            return range;
+        }
+        match scale {
+            Scale::Linear => padded(range, padding),
+            Scale::Log10 => {
+                let range = padded(range.start.log10()..range.end.log10(), padding);
+                10f64.powf(range.start)..10f64.powf(range.end)
+            }
+        }
+    }
+    /// Returns the colormap of the colorbar
+    fn colorbar_cmap(&self) -> colorous::Gradient {
+        self.colorbar
+            .as_ref()
+            .and_then(|colorbar| colorbar.cmap)
+            .unwrap_or(self.cmap)
+    }
+    /// Returns the colors of the colormap sampled at [`Config::cmap_lut_size`] evenly spaced values
+    fn cmap_lut(&self) -> Vec<RGBColor> {
+        let n = self.cmap_lut_size;
+        (0..n)
+            .map(|k| {
+                let c = self.cmap.eval_rational(k, n).as_tuple();
+                RGBColor(c.0, c.1, c.2)
+            })
+            .collect()
+    }
+    /// Returns true if the colorbar is log10 scaled
+    fn colorbar_log(&self) -> bool {
+        self.colorbar.as_ref().is_some_and(|colorbar| colorbar.log)
+    }
+    /// Returns the colormap range with each bound given by [`Config::cmap_min`] or [`Config::cmap_max`],
+    /// or else by the [`Colorbar`] range, or else by the range of `values`
+    ///
+    /// The non-positive values are skipped on a log10 colorbar
+    /// and constant values are centered in a range of width 1 (a decade on a log10 colorbar)
+    fn cmap_range<I>(&self, values: I) -> Result<Range<f64>>
+    where
+        I: Iterator<Item = f64>,
+    {
+        let log = self.colorbar_log();
+        let range = self.colorbar.as_ref().and_then(|c| c.range.as_ref());
+        let bounds = (
+            self.cmap_min.or(range.map(|range| range.start)),
+            self.cmap_max.or(range.map(|range| range.end)),
+        );
+        let (min, max) = match bounds {
+            (Some(min), Some(max)) => (min, max),
+            (min, max) => {
+                let (data_min, data_max) = values
+                    .filter(|value| !log || *value > 0.)
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), value| {
+                        (a.min(value), b.max(value))
+                    });
+                (min.unwrap_or(data_min), max.unwrap_or(data_max))
+            }
+        };
+        if log && min <= 0. {
+            return Err(Error::NonPositiveOnLogAxis {
+                axis: 'z',
+                value: min,
+            });
+        }
+        Ok(if min < max {
+            min..max
+        } else if min == max && log {
+            min / 10f64.sqrt()..max * 10f64.sqrt()
+        } else if min == max {
+            min - 0.5..max + 0.5
+        } else if log {
+            1f64..10f64
+        } else {
+            0f64..1f64
+        })
+    }
+    /// Draws the colorbar with `n` colors along `range` for the colormap bounds `cmap_range`,
+    /// the colorbar axis is log10 scaled if [`Colorbar::log`] is set
+    /// and is the y-axis, with the labels on the right, if `vertical` is true
+    fn draw_colorbar<DB>(
+        &self,
+        chart: &mut ChartBuilder<DB>,
+        range: Range<f64>,
+        cmap_range: &Range<f64>,
+        n: usize,
+        label: Option<&String>,
+        vertical: bool,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let n = n.max(1);
+        if self.colorbar_log() {
+            let (a, b) = (range.start.log10(), range.end.log10());
+            let edges: Vec<_> = (0..=n)
+                .map(|k| 10f64.powf(a + (b - a) * k as f64 / n as f64))
+                .collect();
+            if vertical {
+                let colorbar = chart.build_cartesian_2d(0f64..1f64, range.log_scale())?;
+                self.colorbar_strip(colorbar, &edges, cmap_range, label, vertical)
+            } else {
+                let colorbar = chart.build_cartesian_2d(range.log_scale(), 0f64..1f64)?;
+                self.colorbar_strip(colorbar, &edges, cmap_range, label, vertical)
+            }
+        } else {
+            let dx = (range.end - range.start) / n as f64;
+            let edges: Vec<_> = (0..=n).map(|k| range.start + k as f64 * dx).collect();
+            if vertical {
+                let colorbar = chart.build_cartesian_2d(0f64..1f64, range)?;
+                self.colorbar_strip(colorbar, &edges, cmap_range, label, vertical)
+            } else {
+                let colorbar = chart.build_cartesian_2d(range, 0f64..1f64)?;
+                self.colorbar_strip(colorbar, &edges, cmap_range, label, vertical)
+            }
+        }
+    }
+    /// Draws the colorbar axis and fills the intervals between the `edges` with the colormap
+    fn colorbar_strip<DB, X, Y>(
+        &self,
+        mut colorbar: ChartContext<DB, Cartesian2d<X, Y>>,
+        edges: &[f64],
+        cmap_range: &Range<f64>,
+        label: Option<&String>,
+        vertical: bool,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+        X: Ranged<ValueType = f64> + ValueFormatter<f64>,
+        Y: Ranged<ValueType = f64> + ValueFormatter<f64>,
+    {
+        let log = self.colorbar_log();
+        let ticks = self.colorbar.as_ref().and_then(|colorbar| colorbar.ticks);
+        let log_formatter = |x: &f64| format!("{:.0e}", x);
+        let mut mesh = colorbar.configure_mesh();
+        mesh.axis_style(WHITE)
+            .label_style((self.font_family(), 14, &WHITE));
+        if vertical {
+            mesh.set_tick_mark_size(LabelAreaPosition::Right, 5);
+            if let Some(label) = label {
+                mesh.y_desc(label);
+            }
+            if let Some(ticks) = ticks {
+                mesh.y_labels(ticks);
+            }
+            if log {
+                mesh.y_label_formatter(&log_formatter);
+            }
+        } else {
+            mesh.set_tick_mark_size(LabelAreaPosition::Bottom, 5);
+            if let Some(label) = label {
+                mesh.x_desc(label);
+            }
+            if let Some(ticks) = ticks {
+                mesh.x_labels(ticks);
+            }
+            if log {
+                mesh.x_label_formatter(&log_formatter);
+            }
+        }
+        mesh.draw()?;
+        let cmap = self.colorbar_cmap();
+        colorbar.draw_series(edges.windows(2).map(|x| {
+            let u = normalize(x[0], cmap_range, log).clamp(0., 1.);
+            let c = cmap.eval_continuous(u).as_tuple();
+            let corners = if vertical {
+                [(0., x[0]), (1., x[1])]
+            } else {
+                [(x[0], 0.), (x[1], 1.)]
+            };
+            Rectangle::new(corners, RGBColor(c.0, c.1, c.2).filled())
+        }))?;
+        Ok(())
+    }
+    /// Sets the grid lines, the number of ticks and the tick labels formatter of both axes of the chart mesh
+    fn mesh_style<'b, X, Y, DB>(&'b self, mesh: &mut MeshStyle<'_, 'b, X, Y, DB>)
+    where
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+        DB: DrawingBackend,
+    {
+        if !self.grid {
+            mesh.disable_mesh();
+        } else if let Some(((r, g, b), alpha)) = self.grid_style {
+            mesh.bold_line_style(RGBColor(r, g, b).mix(alpha))
+                .light_line_style(RGBColor(r, g, b).mix(0.5 * alpha));
+        }
+        if let Some(n) = self.minor_gridlines {
+            mesh.x_max_light_lines(n).y_max_light_lines(n);
+        }
+        if self.xaxis.tick_values.is_some() {
+            // the ticks are drawn by Config::draw_tick_values
+            mesh.x_labels(0);
+        } else if let Some(ticks) = self.xaxis.ticks {
+            mesh.x_labels(ticks);
+        }
+        if let Some(format) = &self.xaxis.format {
+            mesh.x_label_formatter(format);
+        }
+        if self.yaxis.tick_values.is_some() {
+            mesh.y_labels(0);
+        } else if let Some(ticks) = self.yaxis.ticks {
+            mesh.y_labels(ticks);
+        }
+        if let Some(format) = &self.yaxis.format {
+            mesh.y_label_formatter(format);
+        }
+        if self.font.is_some() || self.label_font_size.is_some() {
+            mesh.axis_desc_style((self.font_family(), self.label_font_size.unwrap_or(12)));
+        }
+        if self.font.is_some() || self.tick_font_size.is_some() {
+            mesh.label_style((self.font_family(), self.tick_font_size.unwrap_or(12)));
+        }
+    }
+    /// Draws the ticks, the tick labels and the grid lines at the values set with [`Axis::tick_values`],
+    /// laid out as the ticks of the mesh
+    fn draw_tick_values<DB, X, Y>(
+        &self,
+        fig: &DrawingArea<DB, Shift>,
+        chart: &ChartContext<'_, DB, Cartesian2d<X, Y>>,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+        X: Ranged<ValueType = f64> + ValueFormatter<f64>,
+        Y: Ranged<ValueType = f64> + ValueFormatter<f64>,
+    {
+        let (x_scale, y_scale) = (self.xaxis.scale(), self.yaxis.scale());
+        let (xrange, yrange) = (chart.x_range(), chart.y_range());
+        let area = chart.plotting_area();
+        // the tick marks and the labels are drawn outside of the plotting area, on `fig`
+        let (x0, y0) = fig.get_base_pixel();
+        let to_fig = |point: (f64, f64)| {
+            let (x, y) = area.map_coordinate(&point);
+            (x - x0, y - y0)
+        };
+        let grid_style = match self.grid_style {
+            Some(((r, g, b), alpha)) => RGBColor(r, g, b).mix(alpha),
+            None => BLACK.mix(0.2),
+        };
+        let font =
+            TextStyle::from((self.font_family(), self.tick_font_size.unwrap_or(12)).into_font());
+        for x in self
+            .xaxis
+            .tick_values
+            .iter()
+            .flatten()
+            .map(|x| x / x_scale)
+            .filter(|x| contains(&xrange, *x))
+        {
+            if self.grid {
+                area.draw(&PathElement::new(
+                    vec![(x, yrange.start), (x, yrange.end)],
+                    grid_style,
+                ))?;
+            }
+            let (px, py) = to_fig((x, yrange.start));
+            fig.draw(&PathElement::new(vec![(px, py), (px, py + 5)], BLACK))?;
+            let label = self
+                .xaxis
+                .format
+                .map_or_else(|| X::format(&x), |format| format(&x));
+            fig.draw_text(
+                &label,
+                &font.pos(Pos::new(HPos::Center, VPos::Top)),
+                (px, py + 10),
+            )?;
+        }
+        for y in self
+            .yaxis
+            .tick_values
+            .iter()
+            .flatten()
+            .map(|y| y / y_scale)
+            .filter(|y| contains(&yrange, *y))
+        {
+            if self.grid {
+                area.draw(&PathElement::new(
+                    vec![(xrange.start, y), (xrange.end, y)],
+                    grid_style,
+                ))?;
+            }
+            let (px, py) = to_fig((xrange.start, y));
+            fig.draw(&PathElement::new(vec![(px - 5, py), (px, py)], BLACK))?;
+            let label = self
+                .yaxis
+                .format
+                .map_or_else(|| Y::format(&y), |format| format(&y));
+            fig.draw_text(
+                &label,
+                &font.pos(Pos::new(HPos::Right, VPos::Center)),
+                (px - 10, py),
+            )?;
+        }
+        Ok(())
+    }
+    /// Draws the horizontal and vertical reference lines across `chart`,
+    /// returns true if any of the lines has a label for the legend
+    fn draw_reference_lines<DB, X, Y>(
+        &self,
+        chart: &mut ChartContext<'_, DB, Cartesian2d<X, Y>>,
+    ) -> Result<bool>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+    {
+        let (x_scale, y_scale) = (self.xaxis.scale(), self.yaxis.scale());
+        let (xrange, yrange) = (chart.x_range(), chart.y_range());
+        let style = RGBColor(128, 128, 128).stroke_width(self.line_width);
+        let lines = self
+            .hlines
+            .iter()
+            .map(|(y, label)| {
+                let y = y / y_scale;
+                ([(xrange.start, y), (xrange.end, y)], label)
+            })
+            .chain(self.vlines.iter().map(|(x, label)| {
+                let x = x / x_scale;
+                ([(x, yrange.start), (x, yrange.end)], label)
+            }));
+        let mut labeled = false;
+        for (points, label) in lines {
+            let series = chart.draw_series(DashedLineSeries::new(points, 5, 3, style))?;
+            if let Some(label) = label {
+                labeled = true;
+                series
+                    .label(label)
+                    .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+            }
+        }
+        Ok(labeled)
+    }
+    /// Writes the annotations within the axis ranges of `chart`
+    fn draw_annotations<DB, X, Y>(
+        &self,
+        chart: &mut ChartContext<'_, DB, Cartesian2d<X, Y>>,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+    {
+        let (x_scale, y_scale) = (self.xaxis.scale(), self.yaxis.scale());
+        let (xrange, yrange) = (chart.x_range(), chart.y_range());
+        let within = |value: f64, range: &Range<f64>| {
+            value >= range.start.min(range.end) && value <= range.start.max(range.end)
+        };
+        let (size, (r, g, b)) = self.annotation_style;
+        let font = (self.font_family(), size)
+            .into_font()
+            .color(&RGBColor(r, g, b));
+        chart.draw_series(
+            self.annotations
+                .iter()
+                .map(|(x, y, text)| (x / x_scale, y / y_scale, text))
+                .filter(|(x, y, _)| within(*x, &xrange) && within(*y, &yrange))
+                .map(|(x, y, text)| Text::new(text.clone(), (x, y), font.clone())),
+        )?;
+        Ok(())
+    }
+    /// Invokes the chart callback on `chart`
+    fn on_chart<DB, X, Y>(&self, chart: &mut ChartContext<'_, DB, Cartesian2d<X, Y>>) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+    {
+        match &self.chart_callback {
+            Some(ChartCallback(callback)) => Ok(callback(chart)?),
+            None => Ok(()),
+        }
+    }
+    /// Invokes the post-draw callback on the drawing area `root`
+    fn post_draw<DB>(&self, root: &DrawingArea<DB, Shift>) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        match &self.draw_callback {
+            Some(DrawCallback(callback)) => Ok(callback(root)?),
+            None => Ok(()),
+        }
+    }
+}
+/// Draws the configured markers at `points`
+fn draw_markers<'a, 'c, DB, X, Y, I>(
+    chart: &'c mut ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    points: I,
+    style: ShapeStyle,
+    config: &Config,
+) -> Result<&'c mut SeriesAnno<'a, DB>>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+    I: Iterator<Item = (f64, f64)>,
+{
+    let size = config.marker_size as i32;
+    let (xrange, yrange) = (chart.x_range(), chart.y_range());
+    let points =
+        points.filter(|(x, y)| !config.clip || (contains(&xrange, *x) && contains(&yrange, *y)));
+    let series = match config.marker {
+        MarkerShape::Circle => {
+            chart.draw_series(points.map(|point| Circle::new(point, size, style)))?
+        }
+        MarkerShape::Square => chart.draw_series(points.map(|point| {
+            EmptyElement::at(point) + Rectangle::new([(-size, -size), (size, size)], style)
+        }))?,
+        MarkerShape::Cross => {
+            chart.draw_series(points.map(|point| Cross::new(point, size, style)))?
+        }
+        MarkerShape::TriangleUp => {
+            chart.draw_series(points.map(|point| TriangleMarker::new(point, size, style)))?
+        }
+    };
+    Ok(series)
+}
+/// Draws a line with the configured stroke width and style, adding `label` to the legend
+///
+/// The line is broken at the points with a NaN coordinate
+fn draw_line<'a, DB, X, Y, I>(
+    chart: &mut ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    points: I,
+    color: RGBColor,
+    label: Option<&str>,
+    config: &Config,
+) -> Result<()>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+    I: Iterator<Item = (f64, f64)>,
+{
+    let width = config.line_width;
+    let style = color.stroke_width(width);
+    let points: Vec<_> = points.collect();
+    let mut label = label;
+    for segment in points
+        .split(|(x, y)| x.is_nan() || y.is_nan())
+        .filter(|segment| !segment.is_empty())
+    {
+        let points = steps(segment.iter().cloned(), config.line_mode);
+        let paths = if config.clip {
+            clip_path(&points, &chart.x_range(), &chart.y_range())
+        } else {
+            vec![points]
+        };
+        for points in paths {
+            let points = points.into_iter();
+            let series = match config.line_style {
+                LineStyle::Solid => chart.draw_series(LineSeries::new(points, style))?,
+                LineStyle::Dashed => {
+                    chart.draw_series(DashedLineSeries::new(points, 5 * width, 3 * width, style))?
+                }
+                LineStyle::Dotted => {
+                    chart.draw_series(DottedLineSeries::new(points, 0, 3 * width, move |c| {
+                        Circle::new(c, width, color.filled())
+                    }))?
+                }
+            };
+            // only the first segment is added to the legend
+            if let Some(label) = label.take() {
+                series
+                    .label(label)
+                    .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+            }
+        }
+    }
+    Ok(())
+}
+/// Returns true if `value` is within `range`, bounds included, whatever the order of the bounds
+fn contains(range: &Range<f64>, value: f64) -> bool {
+    range.start.min(range.end) <= value && value <= range.start.max(range.end)
+}
+/// Clips the path joining `points` to the rectangle `xrange` by `yrange`,
+/// the path is cut into the parts inside the rectangle with the points where it crosses the boundaries
+fn clip_path(
+    points: &[(f64, f64)],
+    xrange: &Range<f64>,
+    yrange: &Range<f64>,
+) -> Vec<Vec<(f64, f64)>> {
+    if let [point] = points {
+        return if contains(xrange, point.0) && contains(yrange, point.1) {
+            vec![vec![*point]]
+        } else {
+            vec![]
+        };
+    }
+    let (x_min, x_max) = (xrange.start.min(xrange.end), xrange.start.max(xrange.end));
+    let (y_min, y_max) = (yrange.start.min(yrange.end), yrange.start.max(yrange.end));
+    let mut paths = Vec::new();
+    let mut path = Vec::new();
+    for segment in points.windows(2) {
+        let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+        let (dx, dy) = (x1 - x0, y1 - y0);
+        // Liang-Barsky: the part of the segment inside the rectangle is between t0 and t1
+        let (mut t0, mut t1) = (0f64, 1f64);
+        let visible = [
+            (-dx, x0 - x_min),
+            (dx, x_max - x0),
+            (-dy, y0 - y_min),
+            (dy, y_max - y0),
+        ]
+        .iter()
+        .all(|&(p, q)| {
+            if p == 0. {
+                q >= 0.
+            } else {
+                if p < 0. {
+                    t0 = t0.max(q / p);
+                } else {
+                    t1 = t1.min(q / p);
+                }
+                t0 <= t1
+            }
+        });
+        if !visible {
+            if !path.is_empty() {
+                paths.push(std::mem::take(&mut path));
+            }
+            continue;
+        }
+        if path.is_empty() || t0 > 0. {
+            if !path.is_empty() {
+                paths.push(std::mem::take(&mut path));
+            }
+            path.push((x0 + t0 * dx, y0 + t0 * dy));
+        }
+        path.push((x0 + t1 * dx, y0 + t1 * dy));
+        if t1 < 1. {
+            paths.push(std::mem::take(&mut path));
+        }
+    }
+    if !path.is_empty() {
+        paths.push(path);
+    }
+    paths
+}
+/// Maps `value` within `range` onto `[0,1]`, in log10 space if `log` is set
+/// where the non-positive values are mapped to 0
+fn normalize(value: f64, range: &Range<f64>, log: bool) -> f64 {
+    if !log {
+        (value - range.start) / (range.end - range.start)
+    } else if value <= 0. {
+        0.
+    } else {
+        let (a, b) = (range.start.log10(), range.end.log10());
+        (value.log10() - a) / (b - a)
+    }
+}
+/// Inserts the corners of the stairs between consecutive points according to `mode`
+fn steps<I: Iterator<Item = (f64, f64)>>(points: I, mode: LineMode) -> Vec<(f64, f64)> {
+    let mut stairs = Vec::new();
+    for (x, y) in points {
+        match (mode, stairs.last()) {
+            (LineMode::PreStep, Some(&(x0, _))) => stairs.push((x0, y)),
+            (LineMode::PostStep, Some(&(_, y0))) => stairs.push((x, y0)),
+            _ => (),
+        }
+        stairs.push((x, y));
+    }
+    stairs
 }
 trait Utils {
     fn xy_max(data: &[(f64, Vec<f64>)]) -> (f64, f64) {
@@ -285,19 +3504,10 @@ trait Utils {
                 )
             })
     }
-    fn xy_range(data: &[(f64, Vec<f64>)]) -> (Range<f64>, Range<f64>) {
-        let (x_max, y_max) = Self::xy_max(data);
-        let (x_min, y_min) = Self::xy_min(data);
-        assert!(
-            x_max > x_min,
-            "Incorrect x axis range: {:?}",
-            [x_min, x_max]
-        );
-        assert!(
-            y_max > y_min,
-            "Incorrect y axis range: {:?}",
-            [y_min, y_max]
-        );
-        (x_min..x_max, y_min..y_max)
+    fn check_monotonic_x(data: &[(f64, Vec<f64>)]) -> Result<()> {
+        match data.windows(2).position(|xy| xy[1].0 < xy[0].0) {
+            Some(k) => Err(Error::UnsortedX { index: k + 1 }),
+            None => Ok(()),
+        }
     }
 }
@@ -1,10 +1,36 @@
-use crate::{canvas, Axis, Config, Utils};
-use colorous;
-use plotters::prelude::*;
-use std::{iter::FromIterator, path::Path};
+use crate::{draw_line, draw_markers, Config, Draw, Scale, Utils};
+use num_traits::cast::AsPrimitive;
+use plotters::{
+    coord::{ranged1d::ValueFormatter, Shift},
+    prelude::*,
+};
+use std::iter::FromIterator;
 
 /// Line plots
-pub struct Plot;
+///
+/// The lines are broken at the NaN values
+/// ```
+/// let _: complot::Plot = (
+///     (0..100).map(|k| {
+///         let x = k as f64 / 10.;
+///         (x, vec![if (40..60).contains(&k) { f64::NAN } else { x.sin() }])
+///     }),
+///     Some(complot::Config::new().filename("gaps.svg")),
+/// )
+///     .into();
+/// ```
+///
+/// The path of the written file is returned by `path()`, as for all the graphs
+/// ```
+/// let plot: complot::Plot = (0..10).map(|k| (k as f64, vec![k as f64])).collect();
+/// assert_eq!(plot.path(), "complot-plot.svg");
+/// let loglog: complot::LogLog = ((1..10).map(|k| (k as f64, vec![k as f64])), None).into();
+/// assert_eq!(loglog.path(), "complot-loglog.svg");
+/// ```
+pub struct Plot {
+    path: String,
+}
+impl_path!(Plot);
 impl Utils for Plot {}
 /*impl FromIterator<f64> for Plot {
     fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
@@ -35,7 +61,7 @@ impl Utils for Plot {}
 
 /// Plots different lines (x,y1), (x,y2), ... with the data formated into an iterator
 /// where each item is the tuple `(x[i], vec![y1[i], y2[i], ...])`,
-/// the graph is written in the file `plot.svg`
+/// the graph is written in the file `complot-plot.svg`
 /// ```
 ///(0..100).map(|k| {
 ///                   let o = 5.*std::f64::consts::PI*k as f64/100.;
@@ -45,257 +71,555 @@ impl Utils for Plot {}
 ///```
 impl FromIterator<(f64, Vec<f64>)> for Plot {
     fn from_iter<I: IntoIterator<Item = (f64, Vec<f64>)>>(iter: I) -> Self {
-        let fig = canvas("complot-plot.svg", (768, 512)); //SVGBackend::new("complot-plot.svg", (768, 512)).into_drawing_area();
-                                                          //        fig.fill(&WHITE).unwrap();
-        let xy: Vec<_> = iter.into_iter().collect();
-        let (x_max, y_max) = Self::xy_max(&xy);
-        let (x_min, y_min) = Self::xy_min(&xy);
-        let mut chart = ChartBuilder::on(&fig)
-            .set_label_area_size(LabelAreaPosition::Left, 50)
-            .set_label_area_size(LabelAreaPosition::Bottom, 40)
-            .margin(10)
-            .build_cartesian_2d(x_min..x_max, y_min..y_max)
-            .unwrap();
-        chart.configure_mesh().draw().unwrap();
-        let n_y = xy[0].1.len();
-        let data: Vec<_> = xy
-            .into_iter()
-            .flat_map(|(x, y)| y.into_iter().map(|y| (x, y)).collect::<Vec<(f64, f64)>>())
-            .collect();
-        let mut colors = colorous::TABLEAU10.iter().cycle();
-        for k in 0..n_y {
-            let this_color = colors.next().unwrap().as_tuple();
-            chart
-                .draw_series(LineSeries::new(
-                    data.iter().skip(k).step_by(n_y).cloned(),
-                    RGBColor(this_color.0, this_color.1, this_color.2),
-                ))
-                .unwrap();
-        }
-        Plot {}
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-plot.svg")),
+        )
+            .into()
     }
 }
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+type Result<T> = std::result::Result<T, crate::Error>;
 
-impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for Plot {
-    fn from((iter, config): (I, Option<Config>)) -> Self {
-        fn inner<I: Iterator<Item = (f64, Vec<f64>)>>(
-            (iter, config): (I, Option<Config>),
-        ) -> Result<()> {
-            let config = config.unwrap_or_default();
-            let filename = config
-                .filename
-                .unwrap_or_else(|| "complot-plot".to_string());
-            let path = if cfg!(feature = "png") {
-                Path::new(&filename).with_extension("png")
-            } else {
-                Path::new(&filename).with_extension("svg")
-            };
+/// Draws the mesh and the lines on a chart of `fig` with either linear or logarithmic axes
+fn draw_lines<'a, DB, X, Y>(
+    fig: &DrawingArea<DB, Shift>,
+    mut chart: ChartContext<'a, DB, Cartesian2d<X, Y>>,
+    xy: &[(f64, Vec<f64>)],
+    config: &Config,
+) -> Result<()>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+    X: Ranged<ValueType = f64> + ValueFormatter<f64>,
+    Y: Ranged<ValueType = f64> + ValueFormatter<f64>,
+{
+    let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+    let mut mesh = chart.configure_mesh();
+    config.mesh_style(&mut mesh);
+    if let Some(value) = config.xaxis.scaled_label() {
+        mesh.x_desc(value);
+    }
+    if let Some(value) = config.yaxis.scaled_label() {
+        mesh.y_desc(value);
+    }
+    mesh.draw()?;
+    config.draw_tick_values(fig, &chart)?;
 
-            let fig = canvas(path.to_str().unwrap(), (768, 512));
-            fig.fill(&WHITE)?;
-            let xy: Vec<_> = iter.collect();
-            let (x_max, y_max) = Plot::xy_max(&xy);
-            let (x_min, y_min) = Plot::xy_min(&xy);
-            assert!(
-                x_max > x_min,
-                "Incorrect x axis range: {:?}",
-                [x_min, x_max]
-            );
-            assert!(
-                y_max > y_min,
-                "Incorrect y axis range: {:?}",
-                [y_min, y_max]
-            );
+    let n_y = xy.first().map_or(0, |(_, y)| y.len());
+    let data: Vec<_> = xy
+        .iter()
+        .flat_map(|(x, y)| {
+            y.iter()
+                .map(|&y| (*x / x_scale, y / y_scale))
+                .collect::<Vec<(f64, f64)>>()
+        })
+        .collect();
+    for k in 0..n_y {
+        let rgb = config.series_color(k);
+        let label = config
+            .legend
+            .as_ref()
+            .and_then(|legend| legend.get(k))
+            .map(|key| key.as_str());
+        if let Some(window) = config.rolling_mean {
+            // the raw data are lightened and the legend goes to the moving average
+            let RGBColor(r, g, b) = rgb;
+            let lighten = |c: u8| c + (255 - c) / 2;
+            let series: Vec<_> = data.iter().skip(k).step_by(n_y).cloned().collect();
+            draw_line(
+                &mut chart,
+                series.iter().cloned(),
+                RGBColor(lighten(r), lighten(g), lighten(b)),
+                None,
+                config,
+            )?;
+            draw_line(
+                &mut chart,
+                Plot::rolling_mean(&series, window).into_iter(),
+                rgb,
+                label,
+                config,
+            )?;
+        } else {
+            draw_line(
+                &mut chart,
+                data.iter().skip(k).step_by(n_y).cloned(),
+                rgb,
+                label,
+                config,
+            )?;
+        }
+        if config.markers {
+            draw_markers(
+                &mut chart,
+                data.iter()
+                    .skip(k)
+                    .step_by(n_y)
+                    .filter(|(x, y)| !(x.is_nan() || y.is_nan()))
+                    .cloned(),
+                rgb.filled(),
+                config,
+            )?;
+        }
+    }
+    let labeled = config.draw_reference_lines(&mut chart)?;
+    config.draw_annotations(&mut chart)?;
+    config.on_chart(&mut chart)?;
+    if labeled
+        || config
+            .legend
+            .as_ref()
+            .is_some_and(|legend| !legend.is_empty())
+    {
+        config.draw_legend(fig, &mut chart, SeriesLabelPosition::UpperRight)?;
+    }
+    Ok(())
+}
 
-            let mut chart = ChartBuilder::on(&fig)
-                .set_label_area_size(LabelAreaPosition::Left, 50)
-                .set_label_area_size(LabelAreaPosition::Bottom, 40)
-                .margin(10)
-                .build_cartesian_2d(x_min..x_max, y_min..y_max)?;
-            let mut mesh = chart.configure_mesh();
-            if let Some(value) = config.xaxis.label {
-                mesh.x_desc(value);
+impl Draw for Plot {
+    type Data = [(f64, Vec<f64>)];
+    fn draw<DB>(xy: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        let (xrange, yrange) = if xy.is_empty() {
+            // an empty chart
+            let range = |scale| match scale {
+                Scale::Linear => 0f64..1f64,
+                Scale::Log10 => 1f64..10f64,
+            };
+            (
+                config.xaxis.range.clone().unwrap_or(range(config.xscale)),
+                config.yaxis.range.clone().unwrap_or(range(config.yscale)),
+            )
+        } else {
+            let (x_max, y_max) = Plot::xy_max(xy);
+            let (x_min, y_min) = Plot::xy_min(xy);
+            if config.xscale == Scale::Log10 && x_min <= 0. {
+                return Err(crate::Error::NonPositiveOnLogAxis {
+                    axis: 'x',
+                    value: x_min,
+                });
             }
-            if let Some(value) = config.yaxis.label {
-                mesh.y_desc(value);
+            if config.yscale == Scale::Log10 && y_min <= 0. {
+                return Err(crate::Error::NonPositiveOnLogAxis {
+                    axis: 'y',
+                    value: y_min,
+                });
             }
-            mesh.draw()?;
-
-            let n_y = xy[0].1.len();
-            let data: Vec<_> = xy
-                .into_iter()
-                .flat_map(|(x, y)| y.into_iter().map(|y| (x, y)).collect::<Vec<(f64, f64)>>())
-                .collect();
-            let mut colors = colorous::TABLEAU10.iter().cycle();
-            if let Some(legend) = config.legend {
-                for (k, key) in (0..n_y).zip(legend.into_iter()) {
-                    let this_color = colors
-                        .next()
-                        .ok_or("Couldn't get another color.")?
-                        .as_tuple();
-                    let rgb = RGBColor(this_color.0, this_color.1, this_color.2);
-                    chart
-                        .draw_series(LineSeries::new(
-                            data.iter().skip(k).step_by(n_y).cloned(),
-                            &rgb,
-                            //BLACK.mix(0.25),
-                        ))?
-                        .label(key)
-                        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &rgb));
-                }
-                chart
-                    .configure_series_labels()
-                    .border_style(&BLACK)
-                    .background_style(&WHITE.mix(0.8))
-                    .position(SeriesLabelPosition::UpperRight)
-                    .draw()
-                    .unwrap();
-            } else {
-                for k in 0..n_y {
-                    let this_color = colors
-                        .next()
-                        .ok_or("Couldn't get another color.")?
-                        .as_tuple();
-                    chart.draw_series(LineSeries::new(
-                        data.iter().skip(k).step_by(n_y).cloned(),
-                        RGBColor(this_color.0, this_color.1, this_color.2),
-                        //BLACK.mix(0.25),
-                    ))?;
+            let xrange = config
+                .xaxis
+                .range
+                .clone()
+                .unwrap_or_else(|| config.pad_range(x_min..x_max, config.xscale));
+            let yrange = config.yaxis.range.clone().unwrap_or_else(|| {
+                config.pad_range(
+                    config.symmetric_y_range(y_min..y_max, config.yscale),
+                    config.yscale,
+                )
+            });
+            let (xrange, yrange) = match (config.xscale, config.yscale) {
+                (Scale::Linear, Scale::Linear) => {
+                    config.equal_aspect_ranges(fig, 10, (50, 40), (xrange, yrange))?
                 }
+                _ => (xrange, yrange),
+            };
+            (
+                xrange.start / x_scale..xrange.end / x_scale,
+                yrange.start / y_scale..yrange.end / y_scale,
+            )
+        };
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        config.caption(fig, &mut chart, 10)?;
+        match (config.xscale, config.yscale) {
+            (Scale::Linear, Scale::Linear) => {
+                draw_lines(fig, chart.build_cartesian_2d(xrange, yrange)?, xy, config)
             }
-            Ok(())
+            (Scale::Log10, Scale::Linear) => draw_lines(
+                fig,
+                chart.build_cartesian_2d(xrange.log_scale(), yrange)?,
+                xy,
+                config,
+            ),
+            (Scale::Linear, Scale::Log10) => draw_lines(
+                fig,
+                chart.build_cartesian_2d(xrange, yrange.log_scale())?,
+                xy,
+                config,
+            ),
+            (Scale::Log10, Scale::Log10) => draw_lines(
+                fig,
+                chart.build_cartesian_2d(xrange.log_scale(), yrange.log_scale())?,
+                xy,
+                config,
+            ),
         }
-        if let Err(e) = inner((iter, config)) {
-            println!("Complot failed in Plot: {}", e);
+    }
+}
+
+impl Plot {
+    /// Plots the data like the [`From`] conversion but returns the error if the graph cannot be drawn
+    ///
+    /// ```
+    /// fn main() -> complot::Result<()> {
+    ///     complot::Plot::build(
+    ///         (0..100).map(|k| {
+    ///             let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///             (o, vec![o.sin()])
+    ///         }),
+    ///         Some(complot::Config::new().filename("build.svg")),
+    ///     )?;
+    ///     assert!(complot::Plot::build(
+    ///         (0..100).map(|k| (k as f64, vec![k as f64])),
+    ///         Some(complot::Config::new().filename("no/such/directory/build.svg")),
+    ///     )
+    ///     .is_err());
+    ///     // a single point and constant data are centered in axis ranges of width 1
+    ///     complot::Plot::build(
+    ///         std::iter::once((1., vec![1.])),
+    ///         Some(complot::Config::new().filename("single-point.svg")),
+    ///     )?;
+    ///     complot::Plot::build(
+    ///         (0..10).map(|k| (k as f64, vec![1.])),
+    ///         Some(complot::Config::new().filename("constant.svg").ylim(0.0..2.0)),
+    ///     )?;
+    ///     // an empty iterator draws empty axes, within the axis ranges if they are set
+    ///     complot::Plot::build(
+    ///         std::iter::empty(),
+    ///         Some(complot::Config::new().filename("empty.svg")),
+    ///     )?;
+    ///     complot::Plot::build(
+    ///         std::iter::empty(),
+    ///         Some(complot::Config::new().filename("empty-lim.svg").xlim(-5.0..5.0)),
+    ///     )?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn build<I>(iter: I, config: Option<Config>) -> Result<Self>
+    where
+        I: Iterator<Item = (f64, Vec<f64>)>,
+    {
+        let config = config.unwrap_or_default();
+        let xy = Plot::collect(iter, &config)?;
+        let path = config.render::<Plot>(&xy, "complot-plot", (768, 512))?;
+        Ok(Plot { path })
+    }
+    /// Plots the data like [`Plot::build`] but on the drawing area `root` supplied by the caller,
+    /// the filenames and the size of `config` are ignored and `root` is not presented
+    ///
+    /// ```
+    /// use plotters::prelude::*;
+    ///
+    /// fn main() -> complot::Result<()> {
+    ///     let root = SVGBackend::new("draw_on.svg", (1024, 384)).into_drawing_area();
+    ///     root.fill(&WHITE)?;
+    ///     let (left, right) = root.split_horizontally(512);
+    ///     let wave = |f: f64| (0..100).map(move |k| (k as f64, vec![(f * k as f64 / 100.).sin()]));
+    ///     complot::Plot::draw_on(&left, wave(5.), None)?;
+    ///     complot::Scatter::draw_on(&right, wave(20.), None)?;
+    ///     root.present()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn draw_on<DB, I>(
+        root: &DrawingArea<DB, Shift>,
+        iter: I,
+        config: Option<Config>,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+        I: Iterator<Item = (f64, Vec<f64>)>,
+    {
+        let config = config.unwrap_or_default();
+        let xy = Plot::collect(iter, &config)?;
+        config.draw_on::<Plot, DB>(&xy, root)
+    }
+    /// Plots the data like [`Plot::build`] but returns the graph as an SVG string instead of writing it to a file
+    ///
+    /// ```
+    /// fn main() -> complot::Result<()> {
+    ///     let svg = complot::Plot::to_svg_string(
+    ///         (0..100).map(|k| {
+    ///             let o = 5. * std::f64::consts::PI * k as f64 / 100.;
+    ///             (o, vec![o.sin()])
+    ///         }),
+    ///         None,
+    ///     )?;
+    ///     assert!(svg.starts_with("<svg"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_svg_string<I>(iter: I, config: Option<Config>) -> Result<String>
+    where
+        I: Iterator<Item = (f64, Vec<f64>)>,
+    {
+        let config = config.unwrap_or_default();
+        let xy = Plot::collect(iter, &config)?;
+        config.render_svg_string::<Plot>(&xy, (768, 512))
+    }
+    /// Plots the line (xs,ys) like [`Plot::build`]
+    ///
+    /// ```
+    /// fn main() -> complot::Result<()> {
+    ///     let xs: Vec<f64> = (0..100).map(|k| k as f64 / 10.).collect();
+    ///     let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+    ///     complot::Plot::from_xy(&xs, &ys, Some(complot::Config::new().filename("from_xy.svg")))?;
+    ///     assert!(complot::Plot::from_xy(&xs, &ys[1..], None).is_err());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_xy(xs: &[f64], ys: &[f64], config: Option<Config>) -> Result<Self> {
+        Plot::from_columns(xs, &[ys], config)
+    }
+    /// Plots the lines (xs,series\[0\]), (xs,series\[1\]), ... like [`Plot::build`]
+    ///
+    /// Each series must have as many values as `xs`
+    /// ```
+    /// fn main() -> complot::Result<()> {
+    ///     let xs: Vec<f64> = (0..100).map(|k| k as f64 / 10.).collect();
+    ///     let sin: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+    ///     let cos: Vec<f64> = xs.iter().map(|x| x.cos()).collect();
+    ///     complot::Plot::from_columns(
+    ///         &xs,
+    ///         &[&sin, &cos],
+    ///         Some(complot::Config::new().filename("from_columns.svg")),
+    ///     )?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_columns(xs: &[f64], series: &[&[f64]], config: Option<Config>) -> Result<Self> {
+        if let Some((k, ys)) = series
+            .iter()
+            .enumerate()
+            .find(|(_, ys)| ys.len() != xs.len())
+        {
+            return Err(crate::Error::LengthMismatch {
+                series: k,
+                expected: xs.len(),
+                got: ys.len(),
+            });
         }
-        Plot {}
+        Plot::build(
+            xs.iter()
+                .enumerate()
+                .map(|(i, &x)| (x, series.iter().map(|ys| ys[i]).collect())),
+            config,
+        )
+    }
+    /// Plots the data like [`Plot::build`] but writes the graph into `writer` instead of a file
+    ///
+    /// The graph is written as SVG unless a bitmap format is set with [`Config::format`]
+    /// ```
+    /// fn main() -> complot::Result<()> {
+    ///     let mut svg = Vec::new();
+    ///     complot::Plot::write_to(
+    ///         (0..100).map(|k| (k as f64, vec![(k as f64).sqrt()])),
+    ///         None,
+    ///         &mut svg,
+    ///     )?;
+    ///     assert!(svg.starts_with(b"<svg"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn write_to<I, W>(iter: I, config: Option<Config>, writer: W) -> Result<()>
+    where
+        I: Iterator<Item = (f64, Vec<f64>)>,
+        W: std::io::Write,
+    {
+        let config = config.unwrap_or_default();
+        let xy = Plot::collect(iter, &config)?;
+        config.render_to_writer::<Plot, W>(&xy, writer, (768, 512))
+    }
+    /// Collects the data, sorting and checking the x values as set in `config`
+    pub(crate) fn collect<I>(iter: I, config: &Config) -> Result<Vec<(f64, Vec<f64>)>>
+    where
+        I: Iterator<Item = (f64, Vec<f64>)>,
+    {
+        let mut xy: Vec<_> = iter.collect();
+        if config.sort_x {
+            xy.sort_by(|a, b| a.0.total_cmp(&b.0));
+        }
+        if config.require_monotonic_x {
+            Plot::check_monotonic_x(&xy)?;
+        }
+        if let Some((_, y)) = xy.first() {
+            let expected = y.len();
+            if let Some((row, (_, y))) = xy
+                .iter()
+                .enumerate()
+                .find(|(_, (_, y))| y.len() != expected)
+            {
+                return Err(crate::Error::InconsistentSeriesLength {
+                    row,
+                    expected,
+                    got: y.len(),
+                });
+            }
+        }
+        match config.max_points {
+            Some(max_points) if xy.len() > max_points => Ok(Plot::decimate(xy, max_points)),
+            _ => Ok(xy),
+        }
+    }
+    /// Returns the centered moving average of `y` over `window` points,
+    /// the windows shrink at both ends and the NaN values are ignored
+    fn rolling_mean(xy: &[(f64, f64)], window: usize) -> Vec<(f64, f64)> {
+        let n = xy.len();
+        (0..n)
+            .map(|i| {
+                let (x, _) = xy[i];
+                let (sum, count) = xy
+                    [i.saturating_sub((window - 1) / 2)..n.min(i + window / 2 + 1)]
+                    .iter()
+                    .filter(|(_, y)| !y.is_nan())
+                    .fold((0f64, 0usize), |(sum, count), (_, y)| (sum + y, count + 1));
+                (
+                    x,
+                    if count > 0 {
+                        sum / count as f64
+                    } else {
+                        f64::NAN
+                    },
+                )
+            })
+            .collect()
+    }
+    /// Keeps, in each of the `max_points / 2` buckets of consecutive x values,
+    /// the points with the smallest and the largest values of each series and the first gap
+    fn decimate(xy: Vec<(f64, Vec<f64>)>, max_points: usize) -> Vec<(f64, Vec<f64>)> {
+        let bucket_size = xy.len().div_ceil(max_points / 2);
+        xy.chunks(bucket_size)
+            .flat_map(|bucket| {
+                let n_y = bucket[0].1.len();
+                let mut rows: Vec<usize> = (0..n_y)
+                    .flat_map(|k| {
+                        let values = bucket
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, (_, y))| !y[k].is_nan());
+                        let min = values
+                            .clone()
+                            .min_by(|(_, (_, a)), (_, (_, b))| a[k].total_cmp(&b[k]));
+                        let max = values.max_by(|(_, (_, a)), (_, (_, b))| a[k].total_cmp(&b[k]));
+                        min.into_iter().chain(max).map(|(i, _)| i)
+                    })
+                    .chain(
+                        bucket
+                            .iter()
+                            .position(|(_, y)| y.iter().any(|y| y.is_nan())),
+                    )
+                    .collect();
+                rows.sort_unstable();
+                rows.dedup();
+                rows.into_iter().map(move |i| bucket[i].clone())
+            })
+            .collect()
+    }
+}
+
+/// Plots the data of any primitive numeric type, converted to `f64`
+///
+/// ```
+/// let _: complot::Plot = (
+///     (0i32..100).map(|k| (k, vec![(k * k) as u32])),
+///     Some(complot::Config::new().filename("integers.svg")),
+/// )
+///     .into();
+/// ```
+impl<I, X, Y> From<(I, Option<Config>)> for Plot
+where
+    I: Iterator<Item = (X, Vec<Y>)>,
+    X: AsPrimitive<f64>,
+    Y: AsPrimitive<f64>,
+{
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let iter = iter.map(|(x, y)| (x.as_(), y.into_iter().map(|y| y.as_()).collect()));
+        Plot::build(iter, config).unwrap_or_else(|e| {
+            eprintln!("Complot failed in Plot: {}", e);
+            Plot {
+                path: String::new(),
+            }
+        })
     }
 }
 
 /// Log-log plots
 ///
-/// Like [`Plot`] but for `(log10(x),vec![log10(y),...])` items
-pub struct LogLog;
+/// Like [`Plot`] but with logarithmic x and y axes
+pub struct LogLog {
+    path: String,
+}
+impl_path!(LogLog);
 impl Utils for LogLog {}
 impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for LogLog {
     fn from((iter, config): (I, Option<Config>)) -> Self {
-        let _: Plot = (
-            iter.map(|(x, y)| {
-                (
-                    x.log10(),
-                    y.into_iter().map(|y| y.log10()).collect::<Vec<f64>>(),
-                )
-            }),
-            Some(match config {
-                Some(config) => {
-                    match (&config.filename, &config.xaxis.label, &config.yaxis.label) {
-                        (None, None, None) => config.filename("complot-linlog.svg"),
-                        (None, None, Some(label)) => {
-                            let log10_label = format!("log10( {} )", label);
-                            config
-                                .filename("complot-linlog.svg")
-                                .yaxis(Axis::new().label(log10_label))
-                        }
-                        (None, Some(label), None) => {
-                            let log10_label = format!("log10( {} )", label);
-                            config
-                                .filename("complot-linlog.svg")
-                                .xaxis(Axis::new().label(log10_label))
-                        }
-                        (None, Some(xlabel), Some(ylabel)) => {
-                            let log10_xlabel = format!("log10( {} )", xlabel);
-                            let log10_ylabel = format!("log10( {} )", ylabel);
-                            config
-                                .filename("complot-linlog.svg")
-                                .xaxis(Axis::new().label(log10_xlabel))
-                                .yaxis(Axis::new().label(log10_ylabel))
-                        }
-                        (Some(_), None, None) => config,
-                        (Some(_), None, Some(label)) => {
-                            let log10_label = format!("log10( {} )", label);
-                            config.yaxis(Axis::new().label(log10_label))
-                        }
-                        (Some(_), Some(label), None) => {
-                            let log10_label = format!("log10( {} )", label);
-                            config.xaxis(Axis::new().label(log10_label))
-                        }
-                        (Some(_), Some(xlabel), Some(ylabel)) => {
-                            let log10_xlabel = format!("log10( {} )", xlabel);
-                            let log10_ylabel = format!("log10( {} )", ylabel);
-                            config
-                                .xaxis(Axis::new().label(log10_xlabel))
-                                .yaxis(Axis::new().label(log10_ylabel))
-                        }
-                    }
-                }
-                None => Config::new().filename("complot-linlog.svg"),
-            }),
-        )
-            .into();
-        LogLog
+        let config = config.unwrap_or_default();
+        let config = if config.filename.is_none() {
+            config.filename("complot-loglog.svg")
+        } else {
+            config
+        };
+        let path = Plot::build(iter, Some(config.xscale(Scale::Log10).yscale(Scale::Log10)))
+            .map(|Plot { path }| path)
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in LogLog: {}", e);
+                String::new()
+            });
+        LogLog { path }
     }
 }
 /// Log-Lin plots
 ///
-/// Like [`Plot`] but for `(log10(x),vec![y,...])` items
-pub struct LogLin;
+/// Like [`Plot`] but with a logarithmic x axis
+pub struct LogLin {
+    path: String,
+}
+impl_path!(LogLin);
 impl Utils for LogLin {}
 impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for LogLin {
     fn from((iter, config): (I, Option<Config>)) -> Self {
-        let _: Plot = (
-            iter.map(|(x, y)| (x.log10(), y)),
-            Some(match config {
-                Some(config) => match (&config.filename, &config.xaxis.label) {
-                    (None, None) => config.filename("complot-linlog.svg"),
-                    (None, Some(label)) => {
-                        let log10_label = format!("log10( {} )", label);
-                        config
-                            .filename("complot-linlog.svg")
-                            .yaxis(Axis::new().label(log10_label))
-                    }
-                    (Some(_), Some(label)) => {
-                        let log10_label = format!("log10( {} )", label);
-                        config.yaxis(Axis::new().label(log10_label))
-                    }
-                    (Some(_), None) => config,
-                },
-                None => Config::new().filename("complot-linlog.svg"),
-            }),
-        )
-            .into();
-        LogLin
+        let config = config.unwrap_or_default();
+        let config = if config.filename.is_none() {
+            config.filename("complot-loglin.svg")
+        } else {
+            config
+        };
+        let path = Plot::build(iter, Some(config.xscale(Scale::Log10)))
+            .map(|Plot { path }| path)
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in LogLin: {}", e);
+                String::new()
+            });
+        LogLin { path }
     }
 }
 /// Lin-log plots
 ///
-/// Like [`Plot`] but for `(x,vec![log10(y),...])` items
-pub struct LinLog;
+/// Like [`Plot`] but with a logarithmic y axis
+pub struct LinLog {
+    path: String,
+}
+impl_path!(LinLog);
 impl Utils for LinLog {}
 impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for LinLog {
     fn from((iter, config): (I, Option<Config>)) -> Self {
-        let _: Plot = (
-            iter.map(|(x, y)| (x, y.into_iter().map(|y| y.log10()).collect::<Vec<f64>>())),
-            Some(match config {
-                Some(config) => match (&config.filename, &config.yaxis.label) {
-                    (None, None) => config.filename("complot-linlog.svg"),
-                    (None, Some(label)) => {
-                        let log10_label = format!("log10( {} )", label);
-                        config
-                            .filename("complot-linlog.svg")
-                            .yaxis(Axis::new().label(log10_label))
-                    }
-                    (Some(_), Some(label)) => {
-                        let log10_label = format!("log10( {} )", label);
-                        config.yaxis(Axis::new().label(log10_label))
-                    }
-                    (Some(_), None) => config,
-                },
-                None => Config::new().filename("complot-linlog.svg"),
-            }),
-        )
-            .into();
-        LinLog
+        let config = config.unwrap_or_default();
+        let config = if config.filename.is_none() {
+            config.filename("complot-linlog.svg")
+        } else {
+            config
+        };
+        let path = Plot::build(iter, Some(config.yscale(Scale::Log10)))
+            .map(|Plot { path }| path)
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in LinLog: {}", e);
+                String::new()
+            });
+        LinLog { path }
     }
 }
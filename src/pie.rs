@@ -0,0 +1,97 @@
+use super::{Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Pie charts
+///
+/// Each slice is labeled and annotated with its percentage of the total,
+/// the slices with a zero or negative value are skipped
+/// ```
+/// let _: complot::Pie = (
+///     vec![("A".to_string(), 3.), ("B".to_string(), 2.), ("C".to_string(), 1.)].into_iter(),
+///     Some(complot::Config::new().filename("donut.svg").donut(0.5)),
+/// )
+///     .into();
+/// ```
+pub struct Pie {
+    path: String,
+}
+impl_path!(Pie);
+
+impl Draw for Pie {
+    type Data = [(String, f64)];
+    fn draw<DB>(data: &Self::Data, root: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let (labels, sizes): (Vec<_>, Vec<_>) = data
+            .iter()
+            .filter(|(label, value)| {
+                if *value > 0. {
+                    true
+                } else {
+                    eprintln!(
+                        "Complot: skipping the pie slice {:?} with the non-positive value {}",
+                        label, value
+                    );
+                    false
+                }
+            })
+            .cloned()
+            .unzip();
+        if sizes.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let colors: Vec<_> = config.color_cycle().take(sizes.len()).collect();
+
+        let root = match &config.title {
+            Some(value) => root.titled(value, config.title_font())?,
+            None => root.clone(),
+        };
+        let (width, height) = root.dim_in_pixel();
+        let center = (width as i32 / 2, height as i32 / 2);
+        let radius = 0.35 * width.min(height) as f64;
+        let mut pie = plotters::element::Pie::new(&center, &radius, &sizes, &colors, &labels);
+        pie.start_angle(-90.);
+        pie.label_style((config.font_family(), 16));
+        pie.percentages((config.font_family(), 14).into_font().color(&WHITE));
+        if let Some(donut) = config.donut {
+            pie.donut_hole(donut * radius);
+        }
+        root.draw(&pie)?;
+        Ok(())
+    }
+}
+
+/// Draws the pie chart of the `(label, value)` slices, the graph is written in the file `complot-pie.svg`
+/// ```
+/// vec![("one".to_string(), 1.), ("two".to_string(), 2.)]
+///     .into_iter()
+///     .collect::<complot::Pie>();
+/// ```
+impl FromIterator<(String, f64)> for Pie {
+    fn from_iter<I: IntoIterator<Item = (String, f64)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-pie.svg")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (String, f64)>> From<(I, Option<Config>)> for Pie {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<Pie>(&data, "complot-pie", (768, 768))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Pie: {}", e);
+                String::new()
+            });
+        Pie { path }
+    }
+}
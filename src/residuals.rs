@@ -0,0 +1,140 @@
+use super::{draw_line, draw_markers, Config, Draw, Scale, Utils};
+use plotters::{coord::Shift, prelude::*};
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Data and fit with the residuals panel beneath
+struct Residuals;
+impl Utils for Residuals {}
+
+impl Draw for Residuals {
+    /// The data `(x,y,fit(x))` sorted in increasing order of x
+    type Data = [(f64, f64, f64)];
+    fn draw<DB>(data: &Self::Data, root: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if data.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let (_, height) = root.dim_in_pixel();
+        let (upper, lower) = root.split_vertically(height * 3 / 4);
+
+        let xy: Vec<_> = data.iter().map(|&(x, y, f)| (x, vec![y, f])).collect();
+        let (x_max, y_max) = Residuals::xy_max(&xy);
+        let (x_min, y_min) = Residuals::xy_min(&xy);
+        let xrange = config
+            .xaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| config.pad_range(x_min..x_max, Scale::Linear));
+        let yrange = config
+            .yaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| config.pad_range(y_min..y_max, Scale::Linear));
+        let residuals: Vec<_> = data.iter().map(|&(x, y, f)| (x, y - f)).collect();
+        let r_max = residuals
+            .iter()
+            .fold(0f64, |r_max, (_, r)| r_max.max(r.abs()));
+        let r_max = if r_max > 0. { r_max } else { 1. };
+
+        let (data_color, fit_color) = (config.series_color(0), config.series_color(1));
+
+        // DATA & FIT
+        let mut chart = config.chart_builder(&upper, 10, (50, 20));
+        config.caption(&upper, &mut chart, 10)?;
+        let mut chart = chart.build_cartesian_2d(xrange.clone(), yrange)?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = &config.yaxis.label {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        draw_markers(
+            &mut chart,
+            data.iter().map(|&(x, y, _)| (x, y)),
+            data_color.filled(),
+            config,
+        )?;
+        draw_line(
+            &mut chart,
+            data.iter().map(|&(x, _, f)| (x, f)),
+            fit_color,
+            None,
+            config,
+        )?;
+        config.on_chart(&mut chart)?;
+
+        // RESIDUALS
+        let mut chart = config
+            .chart_builder(&lower, 10, (50, 40))
+            .build_cartesian_2d(xrange.clone(), -r_max..r_max)?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = &config.xaxis.label {
+            mesh.x_desc(value);
+        }
+        mesh.y_desc("Residuals").y_labels(5).draw()?;
+        chart.draw_series(LineSeries::new(
+            vec![(xrange.start, 0.), (xrange.end, 0.)],
+            fit_color,
+        ))?;
+        draw_markers(
+            &mut chart,
+            residuals.into_iter(),
+            data_color.filled(),
+            config,
+        )?;
+        Ok(())
+    }
+}
+
+/// Plots the data `(x,y)` and the fit `fit(x)` above the panel of the residuals `y-fit(x)`
+///
+/// Both panels share the x axis range, padded like the other graphs with [`Config::range_padding`],
+/// the path of the written file is returned
+/// ```
+/// let x: Vec<f64> = (0..50).map(|k| k as f64 / 5.).collect();
+/// let y: Vec<f64> = x.iter().map(|x| 2. * x + 1. + (7. * x).sin()).collect();
+/// complot::with_residuals(
+///     &x,
+///     &y,
+///     |x| 2. * x + 1.,
+///     Some(
+///         complot::Config::new()
+///             .filename("residuals.svg")
+///             .range_padding(0.05),
+///     ),
+/// )
+/// .unwrap();
+/// ```
+/// A single point is centered in axis ranges of width 1 and without data, an error is returned
+/// ```
+/// complot::with_residuals(
+///     &[1.],
+///     &[2.],
+///     |x| 2. * x,
+///     Some(complot::Config::new().filename("single-residual.svg")),
+/// )
+/// .unwrap();
+/// let empty = complot::with_residuals(&[], &[], |x| x, None);
+/// assert!(matches!(empty, Err(complot::Error::EmptyData)));
+/// ```
+pub fn with_residuals<F>(x: &[f64], y: &[f64], fit: F, config: Option<Config>) -> Result<String>
+where
+    F: Fn(f64) -> f64,
+{
+    if x.len() != y.len() {
+        return Err(crate::Error::LengthMismatch {
+            series: 0,
+            expected: x.len(),
+            got: y.len(),
+        });
+    }
+    let config = config.unwrap_or_default();
+    let mut data: Vec<_> = x.iter().zip(y).map(|(&x, &y)| (x, y, fit(x))).collect();
+    data.sort_by(|a, b| a.0.total_cmp(&b.0));
+    config.render::<Residuals>(&data, "complot-residuals", (768, 768))
+}
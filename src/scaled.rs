@@ -0,0 +1,147 @@
+//! Backend magnifying the graphs drawn on another backend
+use plotters::style::{FontDesc, RGBAColor};
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+
+/// Drawing backend laying the graphs out for a size `scale` times smaller than the size of `backend`
+///
+/// The coordinates, the line widths and the font sizes are multiplied by `scale` when drawn on `backend`
+pub(crate) struct ScaledBackend<DB> {
+    backend: DB,
+    scale: f64,
+}
+impl<DB> ScaledBackend<DB> {
+    pub(crate) fn new(backend: DB, scale: f64) -> Self {
+        Self { backend, scale }
+    }
+    fn coord(&self, (x, y): BackendCoord) -> BackendCoord {
+        (
+            (x as f64 * self.scale).round() as i32,
+            (y as f64 * self.scale).round() as i32,
+        )
+    }
+    fn length(&self, length: u32) -> u32 {
+        ((length as f64 * self.scale).round() as u32).max(1)
+    }
+    fn style<S: BackendStyle>(&self, style: &S) -> ScaledStyle {
+        ScaledStyle {
+            color: style.color(),
+            stroke_width: self.length(style.stroke_width()),
+        }
+    }
+}
+
+/// Line style with a scaled stroke width
+struct ScaledStyle {
+    color: BackendColor,
+    stroke_width: u32,
+}
+impl BackendStyle for ScaledStyle {
+    fn color(&self) -> BackendColor {
+        self.color
+    }
+    fn stroke_width(&self) -> u32 {
+        self.stroke_width
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for ScaledBackend<DB> {
+    type ErrorType = DB::ErrorType;
+    fn get_size(&self) -> (u32, u32) {
+        let (width, height) = self.backend.get_size();
+        (
+            (width as f64 / self.scale).round() as u32,
+            (height as f64 / self.scale).round() as u32,
+        )
+    }
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.backend.ensure_prepared()
+    }
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.backend.present()
+    }
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (x0, y0) = self.coord(point);
+        let (x1, y1) = self.coord((point.0 + 1, point.1 + 1));
+        self.backend
+            .draw_rect((x0, y0), (x1 - 1, y1 - 1), &color, true)
+    }
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (from, to, style) = (self.coord(from), self.coord(to), self.style(style));
+        self.backend.draw_line(from, to, &style)
+    }
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (upper_left, bottom_right) = (self.coord(upper_left), self.coord(bottom_right));
+        let style = self.style(style);
+        self.backend
+            .draw_rect(upper_left, bottom_right, &style, fill)
+    }
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.into_iter().map(|point| self.coord(point)).collect();
+        let style = self.style(style);
+        self.backend.draw_path(path, &style)
+    }
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (center, radius, style) = (self.coord(center), self.length(radius), self.style(style));
+        self.backend.draw_circle(center, radius, &style, fill)
+    }
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().map(|point| self.coord(point)).collect();
+        let style = self.style(style);
+        self.backend.fill_polygon(vert, &style)
+    }
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let BackendColor {
+            alpha,
+            rgb: (r, g, b),
+        } = style.color();
+        let style = FontDesc::new(style.family(), style.size() * self.scale, style.style())
+            .transform(style.transform())
+            .color(&RGBAColor(r, g, b, alpha))
+            .pos(style.anchor());
+        let pos = self.coord(pos);
+        self.backend.draw_text(text, &style, pos)
+    }
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
+        self.backend.estimate_text_size(text, style)
+    }
+}
@@ -1,17 +1,19 @@
-use super::{Config, Utils};
-use colorous;
-use plotters::prelude::*;
+use super::{draw_markers, Config, Draw, Scale, Utils};
+use plotters::{coord::Shift, prelude::*};
 use std::iter::FromIterator;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+type Result<T> = std::result::Result<T, crate::Error>;
 
 /// Scatter plots
-pub struct Scatter;
+pub struct Scatter {
+    path: String,
+}
+impl_path!(Scatter);
 impl Utils for Scatter {}
 
 /// Plots scattered data (x,y1), (x,y2), ... with the data formated into an iterator
 /// where each item is the tuple `(x[i], vec![y1[i], y2[i], ...])`,
-/// the graph is written in the file `scatter.svg`
+/// the graph is written in the file `complot-scatter.svg`
 /// ```
 ///(0..100).map(|k| {
 ///                   let o = 5.*std::f64::consts::PI*k as f64/100.;
@@ -21,112 +23,142 @@ impl Utils for Scatter {}
 ///```
 impl FromIterator<(f64, Vec<f64>)> for Scatter {
     fn from_iter<I: IntoIterator<Item = (f64, Vec<f64>)>>(iter: I) -> Self {
-        fn inner<I: IntoIterator<Item = (f64, Vec<f64>)>>(iter: I) -> Result<()> {
-            let fig = SVGBackend::new("complot-scatter.svg", (768, 512)).into_drawing_area();
-            fig.fill(&WHITE).unwrap();
-            let xy: Vec<_> = iter.into_iter().collect();
-            let (x_max, y_max) = Scatter::xy_max(&xy);
-            let (x_min, y_min) = Scatter::xy_min(&xy);
-            let mut chart = ChartBuilder::on(&fig)
-                .set_label_area_size(LabelAreaPosition::Left, 50)
-                .set_label_area_size(LabelAreaPosition::Bottom, 40)
-                .margin(10)
-                .build_cartesian_2d(x_min..x_max, y_min..y_max)
-                .unwrap();
-            chart.configure_mesh().draw().unwrap();
-            let n_y = xy[0].1.len();
-            let data: Vec<_> = xy
-                .into_iter()
-                .flat_map(|(x, y)| y.into_iter().map(|y| (x, y)).collect::<Vec<(f64, f64)>>())
-                .collect();
-            let mut colors = colorous::TABLEAU10.iter().cycle();
-            for k in 0..n_y {
-                let this_color = colors.next().unwrap().as_tuple();
-                chart
-                    .draw_series(data.iter().skip(k).step_by(n_y).cloned().map(|point| {
-                        Circle::new(point, 3, RGBColor(this_color.0, this_color.1, this_color.2))
-                    }))
-                    .unwrap();
-            }
-            Ok(())
-        }
-        if let Err(e) = inner(iter) {
-            eprintln!("Complot failed in `Scatter::FromIterator` : {}", e);
-        }
-        Scatter {}
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-scatter.svg").size(768, 512)),
+        )
+            .into()
     }
 }
 
-impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for Scatter {
-    fn from((iter, config): (I, Option<Config>)) -> Self {
-        let config = config.unwrap_or_default();
-        let filename = config
-            .filename
-            .unwrap_or_else(|| "complot-scatter.svg".to_string());
-
-        let fig = SVGBackend::new(&filename, (768, 768)).into_drawing_area();
-        fig.fill(&WHITE).unwrap();
-        let xy: Vec<_> = iter.collect();
-
-        let (x_max, y_max) = xy.iter().cloned().fold(
-            (f64::NEG_INFINITY, f64::NEG_INFINITY),
-            |(fx, fy), (x, y)| {
-                (
-                    fx.max(x),
-                    fy.max(y.iter().cloned().fold(f64::NEG_INFINITY, |fy, y| fy.max(y))),
-                )
-            },
-        );
-        let (x_min, y_min) =
-            xy.iter()
-                .cloned()
-                .fold((f64::INFINITY, f64::INFINITY), |(fx, fy), (x, y)| {
-                    (
-                        fx.min(x),
-                        fy.min(y.iter().cloned().fold(f64::INFINITY, |fy, y| fy.min(y))),
-                    )
-                });
+impl Draw for Scatter {
+    type Data = [(f64, Vec<f64>)];
+    fn draw<DB>(xy: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let (x_scale, y_scale) = (config.xaxis.scale(), config.yaxis.scale());
+        let ((x_min, x_max), (y_min, y_max)) = if xy.is_empty() {
+            // an empty chart
+            ((0., 1.), (0., 1.))
+        } else {
+            let (x_max, y_max) = Scatter::xy_max(xy);
+            let (x_min, y_min) = Scatter::xy_min(xy);
+            ((x_min, x_max), (y_min, y_max))
+        };
 
-        let xrange = if let Some(xrange) = config.xaxis.range {
-            xrange
+        let xrange = if let Some(xrange) = &config.xaxis.range {
+            xrange.clone()
         } else {
-            x_min..x_max
+            config.pad_range(x_min..x_max, Scale::Linear)
         };
-        let yrange = if let Some(yrange) = config.yaxis.range {
-            yrange
+        let yrange = if let Some(yrange) = &config.yaxis.range {
+            yrange.clone()
         } else {
-            y_min..y_max
+            config.pad_range(
+                config.symmetric_y_range(y_min..y_max, Scale::Linear),
+                Scale::Linear,
+            )
         };
+        let (xrange, yrange) = config.equal_aspect_ranges(fig, 20, (0, 0), (xrange, yrange))?;
 
-        let mut chart = ChartBuilder::on(&fig)
-            //            .set_label_area_size(LabelAreaPosition::Left, 50)
-            //            .set_label_area_size(LabelAreaPosition::Bottom, 40)
-            .margin(20)
-            .build_cartesian_2d(xrange, yrange)
-            .unwrap();
+        let mut chart = config.chart_builder(fig, 20, (0, 0));
+        config.caption(fig, &mut chart, 20)?;
+        let mut chart = chart.build_cartesian_2d(
+            xrange.start / x_scale..xrange.end / x_scale,
+            yrange.start / y_scale..yrange.end / y_scale,
+        )?;
         let mut mesh = chart.configure_mesh();
-        if let Some(value) = config.xaxis.label {
+        config.mesh_style(&mut mesh);
+        if let Some(value) = config.xaxis.scaled_label() {
             mesh.x_desc(value);
         }
-        if let Some(value) = config.yaxis.label {
+        if let Some(value) = config.yaxis.scaled_label() {
             mesh.y_desc(value);
         }
-        mesh.draw().unwrap();
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
 
-        let n_y = xy.iter().nth(0).unwrap().1.len();
+        let n_y = xy.first().map_or(0, |(_, y)| y.len());
         let data: Vec<_> = xy
-            .into_iter()
-            .flat_map(|(x, y)| y.into_iter().map(|y| (x, y)).collect::<Vec<(f64, f64)>>())
+            .iter()
+            .flat_map(|(x, y)| {
+                y.iter()
+                    .map(|&y| (*x / x_scale, y / y_scale))
+                    .collect::<Vec<(f64, f64)>>()
+            })
             .collect();
-        let mut colors = colorous::TABLEAU10.iter().cycle();
         for k in 0..n_y {
-            let this_color = colors.next().unwrap().as_tuple();
-            chart
-                .draw_series(data.iter().skip(k).step_by(n_y).cloned().map(|point| {
-                    Circle::new(point, 3, RGBColor(this_color.0, this_color.1, this_color.2))
-                }))
-                .unwrap();
+            let rgb = config.series_color(k);
+            draw_markers(
+                &mut chart,
+                data.iter().skip(k).step_by(n_y).cloned(),
+                rgb.into(),
+                config,
+            )?;
         }
-        Scatter {}
+        let labeled = config.draw_reference_lines(&mut chart)?;
+        config.draw_annotations(&mut chart)?;
+        config.on_chart(&mut chart)?;
+        if labeled {
+            config.draw_legend(fig, &mut chart, SeriesLabelPosition::UpperRight)?;
+        }
+        Ok(())
+    }
+}
+
+impl Scatter {
+    /// Returns the scatter plot of the data as an SVG string instead of writing it to a file
+    ///
+    /// ```
+    /// fn main() -> complot::Result<()> {
+    ///     let svg = complot::Scatter::to_svg_string(
+    ///         (0..100).map(|k| {
+    ///             let (s, c) = (5. * std::f64::consts::PI * k as f64 / 100.).sin_cos();
+    ///             (c, vec![s])
+    ///         }),
+    ///         None,
+    ///     )?;
+    ///     assert!(svg.contains("<circle"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_svg_string<I>(iter: I, config: Option<Config>) -> Result<String>
+    where
+        I: Iterator<Item = (f64, Vec<f64>)>,
+    {
+        let config = config.unwrap_or_default();
+        let xy: Vec<_> = iter.collect();
+        config.render_svg_string::<Scatter>(&xy, (768, 768))
+    }
+    /// Draws the scatter plot on the drawing area `root` supplied by the caller, see [`Plot::draw_on`](crate::Plot::draw_on)
+    pub fn draw_on<DB, I>(
+        root: &DrawingArea<DB, Shift>,
+        iter: I,
+        config: Option<Config>,
+    ) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+        I: Iterator<Item = (f64, Vec<f64>)>,
+    {
+        let config = config.unwrap_or_default();
+        let xy: Vec<_> = iter.collect();
+        config.draw_on::<Scatter, DB>(&xy, root)
+    }
+}
+
+impl<I: Iterator<Item = (f64, Vec<f64>)>> From<(I, Option<Config>)> for Scatter {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let xy: Vec<_> = iter.collect();
+        let path = config
+            .render::<Scatter>(&xy, "complot-scatter.svg", (768, 768))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in `Scatter::From` : {}", e);
+                String::new()
+            });
+        Scatter { path }
     }
 }
@@ -0,0 +1,200 @@
+use super::{normalize, padded, Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Aggregation of the values of the points falling into the same cell
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Aggregation {
+    /// The mean of the values
+    #[default]
+    Mean,
+    /// The largest value
+    Max,
+    /// The number of points
+    Count,
+}
+
+/// Heatmap of scattered points
+///
+/// Bins the points `(x[i], y[i], value[i])` into a grid of [`Config::grid_resolution`] cells,
+/// aggregates the values in each cell according to [`Config::aggregation`]
+/// and paints the cells without any point with [`Config::no_data_color`]
+/// ```
+/// let _: complot::ScatterHeatmap = (
+///     (0..5000).map(|k| {
+///         let r = (k as f64 * 0.618).fract();
+///         let o = k as f64 * 0.1;
+///         let (x, y) = (r * o.cos(), r * o.sin());
+///         (x, y, (-4. * (x * x + y * y)).exp())
+///     }),
+///     Some(
+///         complot::Config::new()
+///             .filename("scatter-heatmap.png")
+///             .grid_resolution(40, 40)
+///             .aggregation(complot::Aggregation::Mean),
+///     ),
+/// )
+///     .into();
+/// ```
+pub struct ScatterHeatmap {
+    path: String,
+}
+impl_path!(ScatterHeatmap);
+
+impl ScatterHeatmap {
+    /// Returns the aggregated values of the cells, in row-major order from the bottom left corner,
+    /// with the x and y ranges of the grid
+    fn bin(
+        data: &[(f64, f64, f64)],
+        config: &Config,
+    ) -> (Vec<Option<f64>>, std::ops::Range<f64>, std::ops::Range<f64>) {
+        let (nx, ny) = config.grid_resolution;
+        let (x_min, x_max, y_min, y_max) = data.iter().fold(
+            (
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+            ),
+            |(a, b, c, d), (x, y, _)| (a.min(*x), b.max(*x), c.min(*y), d.max(*y)),
+        );
+        let xrange = config
+            .xaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| padded(x_min..x_max, 0.));
+        let yrange = config
+            .yaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| padded(y_min..y_max, 0.));
+        // the sum, the largest value and the number of points in each cell
+        let mut cells = vec![(0f64, f64::NEG_INFINITY, 0usize); nx * ny];
+        for &(x, y, value) in data {
+            let u = (x - xrange.start) / (xrange.end - xrange.start);
+            let v = (y - yrange.start) / (yrange.end - yrange.start);
+            if !(0. ..=1.).contains(&u) || !(0. ..=1.).contains(&v) || value.is_nan() {
+                continue;
+            }
+            let i = ((u * nx as f64) as usize).min(nx - 1);
+            let j = ((v * ny as f64) as usize).min(ny - 1);
+            let (sum, max, count) = &mut cells[j * nx + i];
+            *sum += value;
+            *max = max.max(value);
+            *count += 1;
+        }
+        let cells = cells
+            .into_iter()
+            .map(|(sum, max, count)| match (count, config.aggregation) {
+                (0, _) => None,
+                (_, Aggregation::Mean) => Some(sum / count as f64),
+                (_, Aggregation::Max) => Some(max),
+                (_, Aggregation::Count) => Some(count as f64),
+            })
+            .collect();
+        (cells, xrange, yrange)
+    }
+}
+
+impl Draw for ScatterHeatmap {
+    type Data = [(f64, f64, f64)];
+    fn draw<DB>(data: &Self::Data, root: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        if data.is_empty() {
+            return Err(crate::Error::EmptyData);
+        }
+        let (nx, ny) = config.grid_resolution;
+        let (cells, xrange, yrange) = ScatterHeatmap::bin(data, config);
+        let cells_range = config.cmap_range(cells.iter().flatten().cloned())?;
+        let log = config.colorbar_log();
+
+        let (_, height) = root.dim_in_pixel();
+        let (plot, colorbar) = root.split_vertically(height.saturating_sub(80));
+        let mut chart = config.chart_builder(&plot, 20, (40, 40));
+        config.caption(&plot, &mut chart, 20)?;
+        let mut chart = chart.build_cartesian_2d(xrange.clone(), yrange.clone())?;
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = &config.xaxis.label {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = &config.yaxis.label {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(&plot, &chart)?;
+        let (dx, dy) = (
+            (xrange.end - xrange.start) / nx as f64,
+            (yrange.end - yrange.start) / ny as f64,
+        );
+        let (r, g, b) = config.no_data_color;
+        chart.draw_series(cells.iter().enumerate().map(|(k, cell)| {
+            let (i, j) = ((k % nx) as f64, (k / nx) as f64);
+            let color = match cell {
+                Some(value) => {
+                    let c = config
+                        .cmap
+                        .eval_continuous(normalize(*value, &cells_range, log).clamp(0., 1.))
+                        .as_tuple();
+                    RGBColor(c.0, c.1, c.2)
+                }
+                None => RGBColor(r, g, b),
+            };
+            Rectangle::new(
+                [
+                    (xrange.start + i * dx, yrange.start + j * dy),
+                    (xrange.start + (i + 1.) * dx, yrange.start + (j + 1.) * dy),
+                ],
+                color.filled(),
+            )
+        }))?;
+        config.on_chart(&mut chart)?;
+
+        // COLORBAR
+        colorbar.fill(&BLACK)?;
+        config.draw_colorbar(
+            ChartBuilder::on(&colorbar)
+                .margin_left(20)
+                .margin_right(20)
+                .set_label_area_size(LabelAreaPosition::Bottom, 40),
+            cells_range.clone(),
+            &cells_range,
+            colorbar.dim_in_pixel().0 as usize,
+            config.colorbar.as_ref().and_then(|c| c.label.as_ref()),
+            false,
+        )?;
+        Ok(())
+    }
+}
+
+/// Draws the heatmap of the points formated into an iterator
+/// where each item is the tuple `(x[i], y[i], value[i])`,
+/// the graph is written in the file `complot-scatter-heatmap.png`
+impl FromIterator<(f64, f64, f64)> for ScatterHeatmap {
+    fn from_iter<I: IntoIterator<Item = (f64, f64, f64)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-scatter-heatmap.png")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (f64, f64, f64)>> From<(I, Option<Config>)> for ScatterHeatmap {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<ScatterHeatmap>(&data, "complot-scatter-heatmap.png", (768, 848))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in ScatterHeatmap: {}", e);
+                String::new()
+            });
+        ScatterHeatmap { path }
+    }
+}
@@ -0,0 +1,123 @@
+use super::{draw_line, draw_markers, Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Sparkline
+///
+/// Returns the SVG of the [`Sparkline`] graph of the iterator values with the default [`Config`],
+/// see [`Sparkline`] to write the graph in a file
+/// ```
+/// let svg = complot::sparkline((0..50).map(|k| (k as f64 * 0.3).sin()), (100, 20)).unwrap();
+/// assert!(svg.starts_with("<svg"));
+/// assert!(!svg.contains("<text"));
+/// ```
+/// NaN values break the line
+/// ```
+/// let svg = complot::sparkline(vec![0., 1., f64::NAN, 1., 0.], (100, 20)).unwrap();
+/// assert_eq!(svg.matches("<polyline").count(), 2);
+/// ```
+pub fn sparkline<I: IntoIterator<Item = f64>>(iter: I, size: (u32, u32)) -> Result<String> {
+    let data: Vec<f64> = iter.into_iter().collect();
+    Config {
+        background: None,
+        ..Config::new()
+    }
+    .render_svg_string::<Sparkline>(&data, size)
+}
+
+/// Sparkline graph
+///
+/// The line is drawn through the values, evenly spaced along x, on a transparent canvas without axes, labels nor margins,
+/// the smallest and the largest values are marked with dots if [`Config::min_max_markers`] is set
+/// ```
+/// let _: complot::Sparkline = (
+///     (0..50).map(|k| (k as f64 * 0.3).sin() + k as f64 / 25.),
+///     Some(
+///         complot::Config::new()
+///             .filename("sparkline.svg")
+///             .min_max_markers(true),
+///     ),
+/// )
+///     .into();
+/// ```
+pub struct Sparkline {
+    path: String,
+}
+impl_path!(Sparkline);
+
+impl Draw for Sparkline {
+    type Data = [f64];
+    fn draw<DB>(data: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let values = data.iter().enumerate().filter(|(_, y)| !y.is_nan());
+        let min = values.clone().min_by(|(_, a), (_, b)| a.total_cmp(b));
+        let max = values.max_by(|(_, a), (_, b)| a.total_cmp(b));
+        let (y_min, y_max) = match (min, max) {
+            (Some((_, y_min)), Some((_, y_max))) if y_min < y_max => (*y_min, *y_max),
+            (Some((_, y)), _) => (y - 0.5, y + 0.5),
+            _ => (0., 1.),
+        };
+        let x_max = (data.len().max(2) - 1) as f64;
+        // the only margin leaves room for the markers
+        let margin = if config.min_max_markers {
+            config.marker_size
+        } else {
+            0
+        };
+        let mut chart = ChartBuilder::on(fig)
+            .margin(margin)
+            .build_cartesian_2d(0f64..x_max, y_min..y_max)?;
+        let color = config.series_color(0);
+        draw_line(
+            &mut chart,
+            data.iter().enumerate().map(|(k, y)| (k as f64, *y)),
+            color,
+            None,
+            config,
+        )?;
+        if config.min_max_markers {
+            draw_markers(
+                &mut chart,
+                min.into_iter().chain(max).map(|(k, y)| (k as f64, *y)),
+                color.filled(),
+                config,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Draws the sparkline of the values of an iterator,
+/// the graph is written in the file `complot-sparkline.svg`
+impl FromIterator<f64> for Sparkline {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-sparkline.svg")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = f64>> From<(I, Option<Config>)> for Sparkline {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        // the canvas is always transparent
+        let config = Config {
+            background: None,
+            ..config.unwrap_or_default()
+        };
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<Sparkline>(&data, "complot-sparkline.svg", (100, 20))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Sparkline: {}", e);
+                String::new()
+            });
+        Sparkline { path }
+    }
+}
@@ -1,180 +1,297 @@
 //! Delaunay triangulation
-use super::{canvas, Colorbar, Config};
-use plotters::prelude::*;
+use super::{normalize, Config, Draw, Scale};
+use plotters::{
+    coord::{types::RangedCoordf64, Shift},
+    prelude::*,
+};
+use std::ops::Range;
 
-/// Draw a Delaunay mesh given the triangle vertices `vec![(x1,y1),(x2,y2),(x3,y3)]`
-pub struct Mesh {}
-impl<I: Iterator<Item = Vec<(f64, f64)>>> From<(I, Option<Config>)> for Mesh {
-    fn from((iter, config): (I, Option<Config>)) -> Self {
-        let config = config.unwrap_or_default();
-        let filename = config
-            .filename
-            .unwrap_or_else(|| "complot-tri-mesh.png".to_string());
+type Result<T> = std::result::Result<T, crate::Error>;
 
-        let fig = canvas(&filename, (768, 768));
-        fig.fill(&WHITE).unwrap();
-        let xy: Vec<_> = iter.collect();
-        let (x_max, y_max) = xy
-            .iter()
-            .flatten()
-            .cloned()
-            .reduce(|(a, b), (x, y)| (a.max(x), b.max(y)))
-            .unwrap();
-        let (x_min, y_min) = xy
-            .iter()
-            .flatten()
-            .cloned()
-            .reduce(|(a, b), (x, y)| (a.min(x), b.min(y)))
-            .unwrap();
+/// Returns the axis ranges of the chart drawn on `fig`, derived from the triangle `vertices`
+/// unless they are set in `config`
+fn ranges<'a, I, DB>(
+    vertices: I,
+    fig: &DrawingArea<DB, Shift>,
+    config: &Config,
+) -> Result<(Range<f64>, Range<f64>)>
+where
+    I: Iterator<Item = &'a (f64, f64)>,
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let (x_min, x_max, y_min, y_max) = vertices
+        .fold(None, |bounds, &(x, y)| match bounds {
+            None => Some((x, x, y, y)),
+            Some((a, b, c, d)) => Some((x.min(a), x.max(b), y.min(c), y.max(d))),
+        })
+        .ok_or(crate::Error::EmptyData)?;
+    let xrange = if let Some(xrange) = &config.xaxis.range {
+        xrange.clone()
+    } else {
+        config.pad_range(x_min..x_max, Scale::Linear)
+    };
+    let yrange = if let Some(yrange) = &config.yaxis.range {
+        yrange.clone()
+    } else {
+        config.pad_range(y_min..y_max, Scale::Linear)
+    };
+    config.equal_aspect_ranges(fig, 20, (40, 40), (xrange, yrange))
+}
 
-        let xrange = if let Some(xrange) = config.xaxis.range {
-            xrange
-        } else {
-            x_min..x_max
-        };
-        let yrange = if let Some(yrange) = config.yaxis.range {
-            yrange
+/// Draws the triangles `(vertices, value)` with `draw_triangle` in the color mapped from their values,
+/// above the colorbar
+fn draw_colored<DB, F>(
+    xy: &[(Vec<(f64, f64)>, f64)],
+    root: &DrawingArea<DB, Shift>,
+    config: &Config,
+    draw_triangle: F,
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+    F: Fn(
+        &mut ChartContext<'_, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+        &[(f64, f64)],
+        RGBColor,
+    ) -> Result<()>,
+{
+    let cb_size = 80;
+    let (width, height) = root.dim_in_pixel();
+    let (fig, colorbar) = root.split_vertically(height.saturating_sub(cb_size));
+    let (xrange, yrange) = ranges(xy.iter().flat_map(|(v, _)| v), &fig, config)?;
+
+    let cells_range = config.cmap_range(xy.iter().map(|(_, p)| *p))?;
+    let log = config.colorbar_log();
+
+    let mut chart = config
+        .chart_builder(&fig, 20, (40, 40))
+        .build_cartesian_2d(xrange, yrange)?;
+    let mut mesh = chart.configure_mesh();
+    config.mesh_style(&mut mesh);
+    if let Some(value) = &config.xaxis.label {
+        mesh.x_desc(value);
+    }
+    if let Some(value) = &config.yaxis.label {
+        mesh.y_desc(value);
+    }
+    mesh.draw()?;
+    config.draw_tick_values(&fig, &chart)?;
+    let cmap = config.cmap;
+    for (v, p) in xy {
+        let color = if p.is_nan() {
+            RGBColor(config.nan_color.0, config.nan_color.1, config.nan_color.2)
         } else {
-            y_min..y_max
+            let c = cmap
+                .eval_continuous(normalize(*p, &cells_range, log).clamp(0., 1.))
+                .as_tuple();
+            RGBColor(c.0, c.1, c.2)
         };
+        draw_triangle(&mut chart, v, color)?;
+    }
 
-        let mut chart = ChartBuilder::on(&fig)
-            .set_label_area_size(LabelAreaPosition::Left, 40)
-            .set_label_area_size(LabelAreaPosition::Bottom, 40)
-            .margin(20)
-            .build_cartesian_2d(xrange, yrange)
-            .unwrap();
+    // COLORBAR
+    colorbar.fill(&BLACK)?;
+    config.draw_colorbar(
+        ChartBuilder::on(&colorbar).set_label_area_size(LabelAreaPosition::Bottom, 40),
+        config
+            .colorbar
+            .as_ref()
+            .and_then(|c| c.range.clone())
+            .unwrap_or_else(|| cells_range.clone()),
+        &cells_range,
+        width as usize,
+        config.colorbar.as_ref().and_then(|c| c.label.as_ref()),
+        false,
+    )?;
+    Ok(())
+}
+
+/// Draw a Delaunay mesh given the triangle vertices `vec![(x1,y1),(x2,y2),(x3,y3)]`
+///
+/// ```
+/// let triangles = (0..10).flat_map(|i| {
+///     (0..10).flat_map(move |j| {
+///         let (x, y) = (i as f64, j as f64);
+///         vec![
+///             vec![(x, y), (x + 1., y), (x, y + 1.)],
+///             vec![(x + 1., y), (x + 1., y + 1.), (x, y + 1.)],
+///         ]
+///     })
+/// });
+/// let mesh: complot::tri::Mesh = (
+///     triangles,
+///     Some(complot::Config::new().filename("tri-mesh.svg")),
+/// )
+///     .into();
+/// assert_eq!(mesh.path(), "tri-mesh.svg");
+/// ```
+pub struct Mesh {
+    path: String,
+}
+impl_path!(Mesh);
+impl Draw for Mesh {
+    type Data = [Vec<(f64, f64)>];
+    fn draw<DB>(xy: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let (xrange, yrange) = ranges(xy.iter().flatten(), fig, config)?;
+
+        let mut chart = config
+            .chart_builder(fig, 20, (40, 40))
+            .build_cartesian_2d(xrange, yrange)?;
         let mut mesh = chart.configure_mesh();
-        if let Some(value) = config.xaxis.label {
+        config.mesh_style(&mut mesh);
+        if let Some(value) = &config.xaxis.label {
             mesh.x_desc(value);
         }
-        if let Some(value) = config.yaxis.label {
+        if let Some(value) = &config.yaxis.label {
             mesh.y_desc(value);
         }
-        mesh.draw().unwrap();
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+
+        for v in xy {
+            chart.draw_series(LineSeries::new(v.iter().cycle().take(4).cloned(), &BLACK))?;
+        }
+        Ok(())
+    }
+}
+impl<I: Iterator<Item = Vec<(f64, f64)>>> From<(I, Option<Config>)> for Mesh {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<Mesh>(&data, "complot-tri-mesh.png", (768, 768))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Mesh: {}", e);
+                String::new()
+            });
+        Mesh { path }
+    }
+}
 
-        xy.into_iter().for_each(|v| {
-            chart
-                .draw_series(LineSeries::new(
-                    v.iter().cycle().take(4).map(|(x, y)| (*x, *y)),
-                    &BLACK,
-                ))
-                .unwrap();
-        });
-        Mesh {}
+/// Draw a Delaunay mesh with the edges of each triangle colored by a value
+/// given the triangle vertices and values `(vec![(x1,y1),(x2,y2),(x3,y3)],val)`
+///
+/// ```
+/// let triangles = (0..10).flat_map(|i| {
+///     (0..10).flat_map(move |j| {
+///         let (x, y) = (i as f64, j as f64);
+///         vec![
+///             (vec![(x, y), (x + 1., y), (x, y + 1.)], x + y),
+///             (vec![(x + 1., y), (x + 1., y + 1.), (x, y + 1.)], x + y + 1.),
+///         ]
+///     })
+/// });
+/// let _: complot::tri::ColoredMesh = (
+///     triangles,
+///     Some(
+///         complot::Config::new()
+///             .filename("tri-colored-mesh.svg")
+///             .colorbar(complot::Colorbar::new().range(0f64..40f64)),
+///     ),
+/// )
+///     .into();
+/// // the colorbar axis spans the colorbar range
+/// let svg = std::fs::read_to_string("tri-colored-mesh.svg").unwrap();
+/// assert!(svg.contains("40.0"));
+/// // without triangles, the error is reported instead of panicking
+/// let _: complot::tri::ColoredMesh = (
+///     std::iter::empty(),
+///     Some(complot::Config::new().filename("tri-colored-mesh-empty.svg")),
+/// )
+///     .into();
+/// ```
+pub struct ColoredMesh {
+    path: String,
+}
+impl_path!(ColoredMesh);
+impl Draw for ColoredMesh {
+    type Data = [(Vec<(f64, f64)>, f64)];
+    fn draw<DB>(xy: &Self::Data, root: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        draw_colored(xy, root, config, |chart, v, color| {
+            chart.draw_series(LineSeries::new(
+                v.iter().cycle().take(4).cloned(),
+                color.stroke_width(config.line_width),
+            ))?;
+            Ok(())
+        })
+    }
+}
+impl<I: Iterator<Item = (Vec<(f64, f64)>, f64)>> From<(I, Option<Config>)> for ColoredMesh {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default().with_colorbar();
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<ColoredMesh>(&data, "complot-tri-colored-mesh.png", (768, 768 + 80))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in ColoredMesh: {}", e);
+                String::new()
+            });
+        ColoredMesh { path }
     }
 }
 
 /// Heatmap chart on a Delaunay mesh given the triangle vertices and values `(vec![(x1,y1),(x2,y2),(x3,y3)],val)`
-pub struct Heatmap {}
+///
+/// The colormap bounds are the range of the values unless [`Config::cmap_minmax`],
+/// or else the range of the [`Colorbar`](crate::Colorbar), is set,
+/// e.g. for several heatmaps to share the same color scale,
+/// the triangles with a NaN value are painted with [`Config::nan_color`]
+/// ```
+/// let triangles = |scale: f64| {
+///     (0..10).flat_map(move |i| {
+///         (0..10).flat_map(move |j| {
+///             let (x, y) = (i as f64, j as f64);
+///             vec![
+///                 (vec![(x, y), (x + 1., y), (x, y + 1.)], scale * (x + y)),
+///                 (vec![(x + 1., y), (x + 1., y + 1.), (x, y + 1.)], scale * (x + y + 1.)),
+///             ]
+///         })
+///     })
+/// };
+/// for (scale, filename) in [(1., "tri-heatmap-1.svg"), (0.5, "tri-heatmap-2.png")] {
+///     let _: complot::tri::Heatmap = (
+///         triangles(scale),
+///         Some(complot::Config::new().filename(filename).cmap_minmax((0., 20.))),
+///     )
+///         .into();
+/// }
+/// ```
+pub struct Heatmap {
+    path: String,
+}
+impl_path!(Heatmap);
+impl Draw for Heatmap {
+    type Data = [(Vec<(f64, f64)>, f64)];
+    fn draw<DB>(xy: &Self::Data, root: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        draw_colored(xy, root, config, |chart, v, color| {
+            chart.draw_series(std::iter::once(Polygon::new(v.to_vec(), color.filled())))?;
+            Ok(())
+        })
+    }
+}
 impl<I: Iterator<Item = (Vec<(f64, f64)>, f64)>> From<(I, Option<Config>)> for Heatmap {
     fn from((iter, config): (I, Option<Config>)) -> Self {
         let config = config.unwrap_or_default().with_colorbar();
-        let filename = config
-            .filename
-            .unwrap_or_else(|| "complot-tri-heatmap.png".to_string());
-
-        let size = 768usize;
-        let cb_size = 80;
-        let root = canvas(&filename, (size as u32, size as u32 + cb_size));
-        root.fill(&WHITE).unwrap();
-        let (fig, colorbar) = root.split_vertically(size as u32);
-        let mut xy: Vec<_> = iter.collect();
-        let (x_max, y_max) = xy
-            .iter()
-            .flat_map(|(v, _)| v.clone())
-            .reduce(|(a, b), (x, y)| (a.max(x), b.max(y)))
-            .unwrap();
-        let (x_min, y_min) = xy
-            .iter()
-            .flat_map(|(v, _)| v.clone())
-            .reduce(|(a, b), (x, y)| (a.min(x), b.min(y)))
-            .unwrap();
-
-        let xrange = if let Some(xrange) = config.xaxis.range {
-            xrange
-        } else {
-            x_min..x_max
-        };
-        let yrange = if let Some(yrange) = config.yaxis.range {
-            yrange
-        } else {
-            y_min..y_max
-        };
-
-        let cells_max = xy
-            .iter()
-            .map(|(_, p)| p)
-            .cloned()
-            .fold(f64::NEG_INFINITY, f64::max);
-        let cells_min = xy
-            .iter()
-            .map(|(_, p)| p)
-            .cloned()
-            .fold(f64::INFINITY, f64::min);
-        xy.iter_mut().for_each(|(_, p)| {
-            *p = (*p - cells_min) / (cells_max - cells_min);
-        });
-
-        let mut chart = ChartBuilder::on(&fig)
-            .set_label_area_size(LabelAreaPosition::Left, 40)
-            .set_label_area_size(LabelAreaPosition::Bottom, 40)
-            .margin(20)
-            .build_cartesian_2d(xrange, yrange)
-            .unwrap();
-        let mut mesh = chart.configure_mesh();
-        if let Some(value) = config.xaxis.label {
-            mesh.x_desc(value);
-        }
-        if let Some(value) = config.yaxis.label {
-            mesh.y_desc(value);
-        }
-        mesh.draw().unwrap();
-        let cmap = colorous::CIVIDIS;
-        xy.into_iter().for_each(|(v, p)| {
-            chart
-                .draw_series(std::iter::once(Polygon::new(
-                    v.clone(),
-                    if p.is_nan() {
-                        BLACK.filled()
-                    } else {
-                        let c = cmap.eval_continuous(p).as_tuple();
-                        RGBColor(c.0, c.1, c.2).filled()
-                    },
-                )))
-                .unwrap();
-        });
-
-        // COLORBAR
-        colorbar.fill(&BLACK).unwrap();
-        let mut colorbar_chart = ChartBuilder::on(&colorbar)
-            //    .margin_left(20)
-            //    .margin_right(20)
-            .set_label_area_size(LabelAreaPosition::Bottom, 40)
-            .build_cartesian_2d(cells_min..cells_max, 0f64..1f64)
-            .unwrap();
-        let mut mesh = colorbar_chart.configure_mesh();
-        mesh.axis_style(WHITE)
-            .set_tick_mark_size(LabelAreaPosition::Bottom, 5)
-            .x_label_style(("sans-serif", 14, &WHITE));
-        if let Some(Colorbar {
-            label: Some(label), ..
-        }) = config.colorbar
-        {
-            mesh.x_desc(label);
-        }
-        mesh.draw().unwrap();
-        let dx = (cells_max - cells_min) / (size - 1) as f64;
-        let cmap = colorous::CIVIDIS;
-        colorbar_chart
-            .draw_series((0..size).map(|k| {
-                let x = cells_min + k as f64 * dx;
-                let c = cmap.eval_rational(k, size).as_tuple();
-                Rectangle::new([(x, 0.), (x + dx, 1.)], RGBColor(c.0, c.1, c.2).filled())
-            }))
-            .unwrap();
-        root.present().unwrap();
-
-        Heatmap {}
+        let data: Vec<_> = iter.collect();
+        let path = config
+            .render::<Heatmap>(&data, "complot-tri-heatmap.png", (768, 768 + 80))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in Heatmap: {}", e);
+                String::new()
+            });
+        Heatmap { path }
     }
 }
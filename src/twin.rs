@@ -0,0 +1,172 @@
+use super::{draw_line, Config, Draw};
+use plotters::{coord::Shift, prelude::*};
+use std::iter::FromIterator;
+
+type Result<T> = std::result::Result<T, crate::Error>;
+
+/// Line plots with a secondary y-axis
+///
+/// Each item is the tuple `(x[i], vec![y1[i], y2[i], ...], vec![z1[i], z2[i], ...])`
+/// where the lines (x,y1), (x,y2), ... use the left axis set with [`Config::yaxis`]
+/// and the lines (x,z1), (x,z2), ... use the right axis set with [`Config::secondary_yaxis`],
+/// the legend entries are given for the lines of the left axis first
+/// ```
+/// use complot::{Axis, Config, TwinPlot};
+/// let _: TwinPlot = (
+///     (0..100).map(|k| {
+///         let t = k as f64;
+///         (t, vec![20. + 5. * (t / 20.).sin()], vec![1e5 + 2e3 * (t / 30.).cos()])
+///     }),
+///     Some(
+///         Config::new()
+///             .filename("twin.svg")
+///             .yaxis(Axis::new().label("Temperature [C]"))
+///             .secondary_yaxis(Axis::new().label("Pressure [Pa]"))
+///             .legend(vec!["temperature", "pressure"]),
+///     ),
+/// )
+///     .into();
+/// ```
+pub struct TwinPlot {
+    path: String,
+}
+impl_path!(TwinPlot);
+/// Returns the range of the values in `data`
+fn minmax<'a>(data: impl Iterator<Item = &'a f64>) -> std::ops::Range<f64> {
+    let (min, max) = data.fold((f64::INFINITY, f64::NEG_INFINITY), |(a, b), x| {
+        (a.min(*x), b.max(*x))
+    });
+    if min > max {
+        0f64..1f64
+    } else if min == max {
+        min - 0.5..max + 0.5
+    } else {
+        min..max
+    }
+}
+
+impl Draw for TwinPlot {
+    type Data = [(f64, Vec<f64>, Vec<f64>)];
+    fn draw<DB>(xyz: &Self::Data, fig: &DrawingArea<DB, Shift>, config: &Config) -> Result<()>
+    where
+        DB: DrawingBackend,
+        DB::ErrorType: 'static,
+    {
+        let secondary_yaxis = config.secondary_yaxis.clone().unwrap_or_default();
+        let (x_scale, y_scale, z_scale) = (
+            config.xaxis.scale(),
+            config.yaxis.scale(),
+            secondary_yaxis.scale(),
+        );
+        let xrange = config
+            .xaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| minmax(xyz.iter().map(|(x, _, _)| x)));
+        let yrange = config
+            .yaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| minmax(xyz.iter().flat_map(|(_, y, _)| y.iter())));
+        let zrange = secondary_yaxis
+            .range
+            .clone()
+            .unwrap_or_else(|| minmax(xyz.iter().flat_map(|(_, _, z)| z.iter())));
+
+        let mut chart = config.chart_builder(fig, 10, (50, 40));
+        chart.set_label_area_size(LabelAreaPosition::Right, 70);
+        config.caption(fig, &mut chart, 10)?;
+        let xrange = xrange.start / x_scale..xrange.end / x_scale;
+        let (yrange, zrange) = (
+            yrange.start / y_scale..yrange.end / y_scale,
+            zrange.start / z_scale..zrange.end / z_scale,
+        );
+        let mut chart = chart
+            .build_cartesian_2d(xrange.clone(), yrange.clone())?
+            .set_secondary_coord(xrange, zrange.clone());
+        let mut mesh = chart.configure_mesh();
+        config.mesh_style(&mut mesh);
+        if let Some(value) = config.xaxis.scaled_label() {
+            mesh.x_desc(value);
+        }
+        if let Some(value) = config.yaxis.scaled_label() {
+            mesh.y_desc(value);
+        }
+        mesh.draw()?;
+        config.draw_tick_values(fig, &chart)?;
+        let mut secondary_mesh = chart.configure_secondary_axes();
+        if let Some(value) = secondary_yaxis.scaled_label() {
+            secondary_mesh.y_desc(value);
+        }
+        if let Some(ticks) = secondary_yaxis.ticks {
+            secondary_mesh.y_labels(ticks);
+        }
+        if let Some(format) = &secondary_yaxis.format {
+            secondary_mesh.y_label_formatter(format);
+        }
+        secondary_mesh.draw()?;
+
+        // the lines of the right axis are mapped onto the coordinates of the left axis
+        let to_left = |z: f64| {
+            yrange.start
+                + (z / z_scale - zrange.start) * (yrange.end - yrange.start)
+                    / (zrange.end - zrange.start)
+        };
+        let n_y = xyz.first().map_or(0, |(_, y, _)| y.len());
+        let n_z = xyz.first().map_or(0, |(_, _, z)| z.len());
+        let mut legend = config.legend.iter().flatten();
+        for k in 0..n_y + n_z {
+            let rgb = config.series_color(k);
+            let points = xyz.iter().filter_map(|(x, y, z)| {
+                if k < n_y {
+                    y.get(k).map(|y| (x / x_scale, y / y_scale))
+                } else {
+                    z.get(k - n_y).map(|z| (x / x_scale, to_left(*z)))
+                }
+            });
+            draw_line(
+                &mut chart,
+                points,
+                rgb,
+                legend.next().map(|key| key.as_str()),
+                config,
+            )?;
+        }
+        config.on_chart(&mut chart)?;
+        if config
+            .legend
+            .as_ref()
+            .is_some_and(|legend| !legend.is_empty())
+        {
+            config.draw_legend(fig, &mut chart, SeriesLabelPosition::UpperRight)?;
+        }
+        Ok(())
+    }
+}
+
+/// Plots the lines with the data formated into an iterator
+/// where each item is the tuple `(x[i], vec![y1[i], ...], vec![z1[i], ...])`,
+/// the graph is written in the file `complot-twin.svg`
+impl FromIterator<(f64, Vec<f64>, Vec<f64>)> for TwinPlot {
+    fn from_iter<I: IntoIterator<Item = (f64, Vec<f64>, Vec<f64>)>>(iter: I) -> Self {
+        (
+            iter.into_iter(),
+            Some(Config::new().filename("complot-twin.svg")),
+        )
+            .into()
+    }
+}
+
+impl<I: Iterator<Item = (f64, Vec<f64>, Vec<f64>)>> From<(I, Option<Config>)> for TwinPlot {
+    fn from((iter, config): (I, Option<Config>)) -> Self {
+        let config = config.unwrap_or_default();
+        let xyz: Vec<_> = iter.collect();
+        let path = config
+            .render::<TwinPlot>(&xyz, "complot-twin", (768, 512))
+            .unwrap_or_else(|e| {
+                eprintln!("Complot failed in TwinPlot: {}", e);
+                String::new()
+            });
+        TwinPlot { path }
+    }
+}